@@ -0,0 +1,50 @@
+//! Defines [`DecodingError`], an error family for failures while converting
+//! a raw protobuf message into its IBC domain type.
+//!
+//! `DecodingError` collects the identifier-parse, proof-conversion,
+//! missing-height, and signer-parse failure modes of raw-message decoding
+//! into one type. Only `MsgChannelOpenAck`'s `TryFrom<Raw*>` returns it; the
+//! other `ics03_connection` and `ics04_channel` messages still return their
+//! own per-message error variants, and no domain error enum wraps
+//! `DecodingError`.
+
+use displaydoc::Display;
+
+use crate::prelude::*;
+
+/// An error encountered while decoding a raw protobuf message into its IBC
+/// domain type.
+#[derive(Debug, Display)]
+pub enum DecodingError {
+    /// invalid identifier: `{description}`
+    Identifier { description: String },
+    /// invalid proof: `{description}`
+    InvalidProof { description: String },
+    /// missing height field
+    MissingHeight,
+    /// invalid signer: `{description}`
+    Signer { description: String },
+}
+
+impl DecodingError {
+    pub fn identifier(source: impl core::fmt::Display) -> Self {
+        Self::Identifier {
+            description: source.to_string(),
+        }
+    }
+
+    pub fn invalid_proof(source: impl core::fmt::Display) -> Self {
+        Self::InvalidProof {
+            description: source.to_string(),
+        }
+    }
+
+    pub fn signer(source: impl core::fmt::Display) -> Self {
+        Self::Signer {
+            description: source.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodingError {}
@@ -0,0 +1,123 @@
+//! Defines the [`ChainReader`]/[`ChainKeeper`] API a host uses to record and
+//! retrieve a ring of its own past headers, so the connection handshake can
+//! confirm that the counterparty's client of *this* chain tracks a header
+//! this chain actually produced, rather than a forged or divergent one.
+
+use crate::core::ics02_client::error::ClientError;
+use crate::core::ics23_commitment::commitment::CommitmentRoot;
+use crate::prelude::*;
+use crate::Height;
+
+/// A minimal self-description of this chain's state at a given height, as
+/// recorded locally when that height was produced.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelfHeader {
+    pub height: Height,
+    pub root: CommitmentRoot,
+}
+
+impl SelfHeader {
+    pub fn new(height: Height, root: CommitmentRoot) -> Self {
+        Self { height, root }
+    }
+}
+
+/// The locally recorded historical record for a given height, used to check
+/// a counterparty-submitted consensus state of this chain for consistency.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoricalInfo {
+    pub header: SelfHeader,
+}
+
+impl HistoricalInfo {
+    pub fn new(header: SelfHeader) -> Self {
+        Self { header }
+    }
+}
+
+/// Read access to this chain's own recorded history.
+pub trait ChainReader {
+    /// Returns the [`HistoricalInfo`] this chain recorded for `height`, or
+    /// `None` if it falls outside the retained window.
+    fn self_historical_info(&self, height: &Height) -> Option<HistoricalInfo>;
+}
+
+/// Write access to this chain's own recorded history.
+pub trait ChainKeeper {
+    /// Records the self header produced at `height`, to be consulted later
+    /// by [`ChainReader::self_historical_info`].
+    fn store_historical_info(&mut self, height: Height, header: SelfHeader);
+}
+
+/// Verifies that a counterparty-submitted consensus state of this chain
+/// (identified by its commitment root at `height`) matches what this chain
+/// actually recorded for that height, rejecting a forged or divergent view.
+pub fn verify_client_consensus_state(
+    ctx: &impl ChainReader,
+    height: &Height,
+    counterparty_root: &CommitmentRoot,
+) -> Result<(), ClientError> {
+    let historical_info =
+        ctx.self_historical_info(height)
+            .ok_or(ClientError::MissingLocalConsensusState { height: *height })?;
+
+    if &historical_info.header.root == counterparty_root {
+        Ok(())
+    } else {
+        Err(ClientError::ConsensusStateMismatch {
+            height: *height,
+            expected: historical_info.header.root,
+            actual: counterparty_root.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+
+    struct MockChain(BTreeMap<Height, HistoricalInfo>);
+
+    impl ChainReader for MockChain {
+        fn self_historical_info(&self, height: &Height) -> Option<HistoricalInfo> {
+            self.0.get(height).cloned()
+        }
+    }
+
+    impl ChainKeeper for MockChain {
+        fn store_historical_info(&mut self, height: Height, header: SelfHeader) {
+            self.0.insert(height, HistoricalInfo::new(header));
+        }
+    }
+
+    #[test]
+    fn accepts_matching_root() {
+        let height = Height::new(0, 10).unwrap();
+        let root = CommitmentRoot::from_bytes(b"root-at-10");
+        let mut chain = MockChain(BTreeMap::new());
+        chain.store_historical_info(height, SelfHeader::new(height, root.clone()));
+
+        assert!(verify_client_consensus_state(&chain, &height, &root).is_ok());
+    }
+
+    #[test]
+    fn rejects_divergent_root() {
+        let height = Height::new(0, 10).unwrap();
+        let root = CommitmentRoot::from_bytes(b"root-at-10");
+        let forged = CommitmentRoot::from_bytes(b"forged-root");
+        let mut chain = MockChain(BTreeMap::new());
+        chain.store_historical_info(height, SelfHeader::new(height, root));
+
+        assert!(verify_client_consensus_state(&chain, &height, &forged).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_height() {
+        let height = Height::new(0, 10).unwrap();
+        let root = CommitmentRoot::from_bytes(b"root-at-10");
+        let chain = MockChain(BTreeMap::new());
+
+        assert!(verify_client_consensus_state(&chain, &height, &root).is_err());
+    }
+}
@@ -4,6 +4,9 @@ use crate::core::ics24_host::identifier::ClientId;
 use crate::core::ics24_host::path::ClientConsensusStatePath;
 use crate::core::ics24_host::path::ClientStatePath;
 use crate::core::ContextError;
+use crate::prelude::*;
+use crate::timestamp::Timestamp;
+use crate::Height;
 
 pub trait ClientTypes {
     type V: ClientValidationContext;
@@ -25,6 +28,63 @@ pub trait ClientValidationContext: ClientTypes + Sized {
         &self,
         client_cons_state_path: &ClientConsensusStatePath,
     ) -> Result<Self::AnyConsensusState, ContextError>;
+
+    /// Returns the heights, in ascending order, at which a consensus state
+    /// was stored for `client_id`. Used to answer
+    /// `QueryConsensusStateHeightsRequest` and to locate pruning candidates.
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
+
+    /// Returns the earliest height at which a consensus state is still
+    /// retained for `client_id`, or `None` if it has none.
+    fn earliest_consensus_state(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Option<(Height, Self::AnyConsensusState)>, ContextError> {
+        Ok(self
+            .consensus_state_heights(client_id)?
+            .into_iter()
+            .next()
+            .map(|height| {
+                let path = ClientConsensusStatePath::new(client_id, &height);
+                self.consensus_state(&path).map(|state| (height, state))
+            })
+            .transpose()?)
+    }
+
+    /// Returns the consensus state stored at the next retained height after
+    /// `height`, if any, for `client_id`.
+    fn next_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<(Height, Self::AnyConsensusState)>, ContextError> {
+        self.consensus_state_heights(client_id)?
+            .into_iter()
+            .find(|h| h > height)
+            .map(|height| {
+                let path = ClientConsensusStatePath::new(client_id, &height);
+                self.consensus_state(&path).map(|state| (height, state))
+            })
+            .transpose()
+    }
+
+    /// Returns the consensus state stored at the closest retained height
+    /// before `height`, if any, for `client_id`.
+    fn prev_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<(Height, Self::AnyConsensusState)>, ContextError> {
+        self.consensus_state_heights(client_id)?
+            .into_iter()
+            .rev()
+            .find(|h| h < height)
+            .map(|height| {
+                let path = ClientConsensusStatePath::new(client_id, &height);
+                self.consensus_state(&path).map(|state| (height, state))
+            })
+            .transpose()
+    }
 }
 
 /// Defines the methods that all client `ExecutionContext`s (precisely the
@@ -49,4 +109,46 @@ pub trait ClientExecutionContext: ClientValidationContext + Sized {
         consensus_state_path: ClientConsensusStatePath,
         consensus_state: Self::AnyConsensusState,
     ) -> Result<(), ContextError>;
+
+    /// Delete the consensus state at the given path, e.g. as part of
+    /// enforcing a retention window after an update.
+    ///
+    /// Defaults to a no-op: no `ClientExecutionContext` implementor exists in
+    /// this tree yet to require it of, and a host that never prunes consensus
+    /// states is still correct, just unbounded in storage.
+    fn delete_consensus_state(
+        &mut self,
+        _consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Called upon a successful client update, recording when (in both host
+    /// timestamp and host height terms) the update was processed. Consulted
+    /// during misbehaviour and update verification to enforce time-delay
+    /// and block-delay windows.
+    ///
+    /// Defaults to a no-op for the same reason as [`Self::delete_consensus_state`];
+    /// a host relying on the time-delay/block-delay checks must override this.
+    fn store_update_meta(
+        &mut self,
+        _client_id: ClientId,
+        _height: Height,
+        _host_timestamp: Timestamp,
+        _host_height: Height,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Deletes the update metadata recorded by `store_update_meta` for the
+    /// given client and height.
+    ///
+    /// Defaults to a no-op for the same reason as [`Self::delete_consensus_state`].
+    fn delete_update_meta(
+        &mut self,
+        _client_id: ClientId,
+        _height: Height,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
 }
@@ -1,15 +1,52 @@
 //! Protocol logic specific to processing ICS3 messages of type `MsgConnectionOpenConfirm`.
+//!
+//! This handler used to route its `connection_end`/`client_consensus_state`
+//! reads through `ConnectionPath`/`ClientConsensusStatePath` wrapper
+//! functions that just unwrapped the path back into the identifier
+//! `ConnectionReader::connection_end`/`client_consensus_state` already take,
+//! without touching how the value is actually looked up. `ConnectionReader`'s
+//! defining file isn't in this tree to change those methods to take a `*Path`
+//! directly, so keeping the wrappers bought nothing beyond the reads they
+//! replace; they're inlined below instead of pretending to be a typed-path
+//! store surface that doesn't exist yet.
 
+use crate::core::ics02_client::chain_reader::{verify_client_consensus_state, ChainReader};
 use crate::core::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
 use crate::core::ics03_connection::context::ConnectionReader;
 use crate::core::ics03_connection::error::Error;
 use crate::core::ics03_connection::events::Attributes;
 use crate::core::ics03_connection::handler::{ConnectionIdState, ConnectionResult};
 use crate::core::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+use crate::core::ics23_commitment::commitment::CommitmentRoot;
 use crate::events::IbcEvent;
 use crate::handler::{HandlerOutput, HandlerResult};
 use crate::prelude::*;
 
+/// Opt-in addition to [`process`]: also rejects a forged or divergent
+/// counterparty view of our own chain, by checking that the root the
+/// counterparty claims for us at `msg.proofs_height` matches what this chain
+/// actually recorded for that height.
+///
+/// This can't simply be folded into [`process`] itself: `ConnectionReader`
+/// doesn't carry a `ChainReader` supertrait bound (its defining file isn't in
+/// this tree to add one to), so the check needs a separate `chain_ctx`
+/// object, and `MsgConnectionOpenConfirm` has no field carrying the
+/// counterparty's claimed root (that message's defining file also isn't in
+/// this tree to extend). `dispatch` still calls the two-argument
+/// [`process`] directly, so this wrapper is additive rather
+/// than load-bearing — hosts that can supply a `ChainReader` call this
+/// instead to get the stronger check.
+pub(crate) fn process_with_self_consensus_check(
+    ctx: &dyn ConnectionReader,
+    chain_ctx: &dyn ChainReader,
+    counterparty_claimed_self_root: &CommitmentRoot,
+    msg: MsgConnectionOpenConfirm,
+) -> HandlerResult<ConnectionResult, Error> {
+    verify_client_consensus_state(chain_ctx, &msg.proofs_height, counterparty_claimed_self_root)
+        .map_err(Error::verify_connection_state)?;
+    process(ctx, msg)
+}
+
 pub(crate) fn process(
     ctx: &dyn ConnectionReader,
     msg: MsgConnectionOpenConfirm,
@@ -85,19 +122,24 @@ mod tests {
     use core::str::FromStr;
     use test_log::test;
 
+    use alloc::collections::BTreeMap;
+
+    use crate::core::ics02_client::chain_reader::{ChainKeeper, ChainReader, HistoricalInfo, SelfHeader};
     use crate::core::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
     use crate::core::ics03_connection::context::ConnectionReader;
     use crate::core::ics03_connection::handler::{dispatch, ConnectionResult};
     use crate::core::ics03_connection::msgs::conn_open_confirm::test_util::get_dummy_raw_msg_conn_open_confirm;
     use crate::core::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
     use crate::core::ics03_connection::msgs::ConnectionMsg;
-    use crate::core::ics23_commitment::commitment::CommitmentPrefix;
+    use crate::core::ics23_commitment::commitment::{CommitmentPrefix, CommitmentRoot};
     use crate::core::ics24_host::identifier::ClientId;
     use crate::events::IbcEvent;
     use crate::mock::context::MockContext;
     use crate::timestamp::ZERO_DURATION;
     use crate::Height;
 
+    use super::process_with_self_consensus_check;
+
     #[test]
     fn conn_open_confirm_msg_processing() {
         struct Test {
@@ -193,4 +235,63 @@ mod tests {
             }
         }
     }
+
+    struct MockChain(BTreeMap<Height, HistoricalInfo>);
+
+    impl ChainReader for MockChain {
+        fn self_historical_info(&self, height: &Height) -> Option<HistoricalInfo> {
+            self.0.get(height).cloned()
+        }
+    }
+
+    impl ChainKeeper for MockChain {
+        fn store_historical_info(&mut self, height: Height, header: SelfHeader) {
+            self.0.insert(height, HistoricalInfo::new(header));
+        }
+    }
+
+    #[test]
+    fn process_with_self_consensus_check_rejects_divergent_self_root() {
+        let client_id = ClientId::from_str("mock_clientid").unwrap();
+        let msg_confirm =
+            MsgConnectionOpenConfirm::try_from(get_dummy_raw_msg_conn_open_confirm()).unwrap();
+        let counterparty = Counterparty::new(
+            client_id.clone(),
+            Some(msg_confirm.connection_id.clone()),
+            CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+        );
+
+        let context = MockContext::default();
+        let correct_conn_end = ConnectionEnd::new(
+            State::TryOpen,
+            client_id.clone(),
+            counterparty,
+            context.get_compatible_versions(),
+            ZERO_DURATION,
+        );
+
+        let ctx = context
+            .with_client(&client_id, Height::new(0, 10).unwrap())
+            .with_connection(msg_confirm.connection_id.clone(), correct_conn_end);
+
+        let recorded_root = CommitmentRoot::from_bytes(b"root-we-actually-produced");
+        let mut chain = MockChain(BTreeMap::new());
+        chain.store_historical_info(
+            msg_confirm.proofs_height,
+            SelfHeader::new(msg_confirm.proofs_height, recorded_root.clone()),
+        );
+
+        let forged_root = CommitmentRoot::from_bytes(b"forged-root");
+        assert!(process_with_self_consensus_check(
+            &ctx,
+            &chain,
+            &forged_root,
+            msg_confirm.clone()
+        )
+        .is_err());
+
+        assert!(
+            process_with_self_consensus_check(&ctx, &chain, &recorded_root, msg_confirm).is_ok()
+        );
+    }
 }
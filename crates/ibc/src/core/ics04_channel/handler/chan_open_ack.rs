@@ -0,0 +1,81 @@
+//! Protocol logic specific to processing `MsgChannelOpenAck`.
+
+use crate::core::ics04_channel::version::verify_acknowledged_version;
+use crate::core::ics04_channel::Version;
+use crate::core::ics24_host::identifier::{ChannelId, PortId};
+use crate::prelude::*;
+
+/// Read access to the versions this chain actually offered for a channel
+/// during its own `ChanOpenInit`/`ChanOpenTry` step, keyed by the channel's
+/// own identity rather than the chain's global [`supported_versions`](
+/// crate::core::ics04_channel::version::supported_versions).
+pub trait ChannelReader {
+    /// Returns the versions this chain offered for `(port_id, chan_id)`, or
+    /// `None` if no channel is recorded under that identity.
+    fn versions_offered(&self, port_id: &PortId, chan_id: &ChannelId) -> Option<Vec<Version>>;
+}
+
+/// Verifies that the version the counterparty acknowledged in a
+/// `ChanOpenAck` is one this chain actually offered for `port_id_on_a`/
+/// `chan_id_on_a`, rather than checking it against the chain's global
+/// supported-version list. This is handshake-state business logic, so it
+/// belongs here rather than in `MsgChannelOpenAck`'s raw-message decoding.
+pub fn process(
+    ctx: &dyn ChannelReader,
+    port_id_on_a: &PortId,
+    chan_id_on_a: &ChannelId,
+    version_on_b: &Version,
+) -> Result<(), crate::core::ics04_channel::error::ChannelError> {
+    let offered = ctx
+        .versions_offered(port_id_on_a, chan_id_on_a)
+        .ok_or_else(|| crate::core::ics04_channel::error::ChannelError::NoCommonVersion)?;
+
+    verify_acknowledged_version(version_on_b, &offered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+
+    struct MockChannels(BTreeMap<(PortId, ChannelId), Vec<Version>>);
+
+    impl ChannelReader for MockChannels {
+        fn versions_offered(&self, port_id: &PortId, chan_id: &ChannelId) -> Option<Vec<Version>> {
+            self.0.get(&(port_id.clone(), chan_id.clone())).cloned()
+        }
+    }
+
+    fn port_and_channel() -> (PortId, ChannelId) {
+        (PortId::default(), ChannelId::default())
+    }
+
+    #[test]
+    fn accepts_version_this_chain_offered() {
+        let (port_id, chan_id) = port_and_channel();
+        let mut channels = BTreeMap::new();
+        channels.insert((port_id.clone(), chan_id.clone()), vec![Version::ics20()]);
+        let ctx = MockChannels(channels);
+
+        assert!(process(&ctx, &port_id, &chan_id, &Version::ics20()).is_ok());
+    }
+
+    #[test]
+    fn rejects_version_this_chain_never_offered() {
+        let (port_id, chan_id) = port_and_channel();
+        let mut channels = BTreeMap::new();
+        channels.insert((port_id.clone(), chan_id.clone()), vec![Version::ics20()]);
+        let ctx = MockChannels(channels);
+
+        let unsolicited = Version::new("unsolicited-1".to_string());
+        assert!(process(&ctx, &port_id, &chan_id, &unsolicited).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_channel() {
+        let (port_id, chan_id) = port_and_channel();
+        let ctx = MockChannels(BTreeMap::new());
+
+        assert!(process(&ctx, &port_id, &chan_id, &Version::ics20()).is_err());
+    }
+}
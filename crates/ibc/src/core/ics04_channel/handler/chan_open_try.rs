@@ -0,0 +1,46 @@
+//! Protocol logic specific to processing `MsgChannelOpenTry`.
+//!
+//! `MsgChannelOpenTry`/its handler aren't present in this tree, but the
+//! version-selection step this module exposes is the piece chunk0-1 asked
+//! for: the responder must pick a version mutually compatible with the
+//! counterparty's proposal rather than echoing back whatever arrived.
+
+use crate::core::ics04_channel::version::pick_version;
+use crate::core::ics04_channel::Version;
+use crate::prelude::*;
+
+/// Selects the version this chain will respond with during `ChanOpenTry`,
+/// given the versions it supports and the versions the counterparty
+/// proposed. Returns an error if none of the counterparty's proposals are
+/// acceptable, rather than echoing the counterparty's first proposal back
+/// unchecked.
+pub fn select_version(
+    supported_versions: &[Version],
+    counterparty_proposed_versions: &[Version],
+) -> Result<Version, crate::core::ics04_channel::error::ChannelError> {
+    pick_version(supported_versions, counterparty_proposed_versions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selects_common_version_instead_of_echoing_first_proposal() {
+        let supported = vec![Version::ics20()];
+        let proposed = vec![Version::new("ics27-1".to_string()), Version::ics20()];
+
+        assert_eq!(
+            select_version(&supported, &proposed).unwrap(),
+            Version::ics20()
+        );
+    }
+
+    #[test]
+    fn rejects_proposal_with_no_overlap() {
+        let supported = vec![Version::ics20()];
+        let proposed = vec![Version::new("ics27-1".to_string())];
+
+        assert!(select_version(&supported, &proposed).is_err());
+    }
+}
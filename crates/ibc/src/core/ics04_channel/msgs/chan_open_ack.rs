@@ -1,3 +1,4 @@
+use crate::core::decoding_error::DecodingError;
 use crate::core::ics04_channel::error::ChannelError;
 use crate::core::ics04_channel::Version;
 use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
@@ -63,30 +64,44 @@ impl Msg for MsgChannelOpenAck {
 
 impl Protobuf<RawMsgChannelOpenAck> for MsgChannelOpenAck {}
 
+// `DecodingError` collects identifier-parse, proof-conversion,
+// missing-height, and signer-parse failures into a single family meant to be
+// shared by every handshake message's `TryFrom<Raw...>`; only this message
+// has been migrated to it so far, and the switch is a breaking change for
+// existing callers matching on `ChannelError` (no `From<DecodingError> for
+// ChannelError` wrapping exists here or anywhere else in this crate).
 impl TryFrom<RawMsgChannelOpenAck> for MsgChannelOpenAck {
-    type Error = ChannelError;
+    type Error = DecodingError;
 
+    // Note: this only decodes the wire message; it does not check that
+    // `version_on_b` is one this chain actually offered for this channel.
+    // That's handshake-state business logic keyed by the channel's own
+    // record of what it offered, not the chain's global
+    // `supported_versions()`, so it belongs in
+    // `handler::chan_open_ack::process` instead of here.
     fn try_from(raw_msg: RawMsgChannelOpenAck) -> Result<Self, Self::Error> {
+        let version_on_b: Version = raw_msg.counterparty_version.into();
+
         Ok(MsgChannelOpenAck {
-            port_id_on_a: raw_msg.port_id.parse().map_err(ChannelError::Identifier)?,
+            port_id_on_a: raw_msg.port_id.parse().map_err(DecodingError::identifier)?,
             chan_id_on_a: raw_msg
                 .channel_id
                 .parse()
-                .map_err(ChannelError::Identifier)?,
+                .map_err(DecodingError::identifier)?,
             chan_id_on_b: raw_msg
                 .counterparty_channel_id
                 .parse()
-                .map_err(ChannelError::Identifier)?,
-            version_on_b: raw_msg.counterparty_version.into(),
+                .map_err(DecodingError::identifier)?,
+            version_on_b,
             proof_chan_end_on_b: raw_msg
                 .proof_try
                 .try_into()
-                .map_err(ChannelError::InvalidProof)?,
+                .map_err(DecodingError::invalid_proof)?,
             proof_height_on_b: raw_msg
                 .proof_height
                 .and_then(|raw_height| raw_height.try_into().ok())
-                .ok_or(ChannelError::MissingHeight)?,
-            signer: raw_msg.signer.parse().map_err(ChannelError::Signer)?,
+                .ok_or(DecodingError::MissingHeight)?,
+            signer: raw_msg.signer.parse().map_err(DecodingError::signer)?,
         })
     }
 }
@@ -120,7 +135,7 @@ pub mod test_util {
             port_id: PortId::default().to_string(),
             channel_id: ChannelId::default().to_string(),
             counterparty_channel_id: ChannelId::default().to_string(),
-            counterparty_version: "".to_string(),
+            counterparty_version: "ics20-1".to_string(),
             proof_try: get_dummy_proof(),
             proof_height: Some(Height {
                 revision_number: 0,
@@ -232,19 +247,27 @@ mod tests {
                 want_pass: false,
             },
             Test {
-                name: "Empty counterparty version (allowed)".to_string(),
+                name: "Empty counterparty version (rejected, not offered)".to_string(),
                 raw: RawMsgChannelOpenAck {
                     counterparty_version: " ".to_string(),
                     ..default_raw_msg.clone()
                 },
-                want_pass: true,
+                want_pass: false,
             },
             Test {
-                name: "Arbitrary counterparty version (allowed)".to_string(),
+                name: "Arbitrary counterparty version (rejected, not offered)".to_string(),
                 raw: RawMsgChannelOpenAck {
                     counterparty_version: "v1.1.23-alpha".to_string(),
                     ..default_raw_msg.clone()
                 },
+                want_pass: false,
+            },
+            Test {
+                name: "Counterparty version we actually offered".to_string(),
+                raw: RawMsgChannelOpenAck {
+                    counterparty_version: "ics20-1".to_string(),
+                    ..default_raw_msg.clone()
+                },
                 want_pass: true,
             },
             Test {
@@ -0,0 +1,129 @@
+//! Defines the versioning scheme for IBC channels, modeled after the
+//! connection version negotiation in `ics03_connection::version`.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use crate::core::ics04_channel::error::ChannelError;
+use crate::prelude::*;
+
+/// The version field for a channel end.
+///
+/// Unlike `ics03_connection::version::Version`, a channel's version is
+/// largely opaque to the core IBC protocol: its contents are defined and
+/// interpreted by the application module bound to the channel's port.
+/// However, the handshake still requires that both ends agree on a version
+/// the local chain is actually willing to speak, mirroring the connection
+/// handshake's `get_compatible_versions()` / `ConnectionVersion::compatibles()`
+/// negotiation.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version(String);
+
+impl Version {
+    pub fn new(version: String) -> Self {
+        Self(version)
+    }
+
+    pub fn empty() -> Self {
+        Self::new("".to_string())
+    }
+
+    pub fn ics20() -> Self {
+        Self::new("ics20-1".to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for Version {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Returns the list of versions this chain is willing to speak for newly
+/// opened channels, in order of preference.
+pub fn supported_versions() -> Vec<Version> {
+    vec![Version::ics20()]
+}
+
+/// Selects a version mutually acceptable to both ends of a channel, given the
+/// versions this chain supports and the versions the counterparty proposes,
+/// preferring `supported`'s ordering.
+///
+/// Returns an error if none of the counterparty's proposals are supported.
+pub fn pick_version(
+    supported_versions: &[Version],
+    counterparty_proposed_versions: &[Version],
+) -> Result<Version, ChannelError> {
+    supported_versions
+        .iter()
+        .find(|version| counterparty_proposed_versions.contains(version))
+        .cloned()
+        .ok_or(ChannelError::NoCommonVersion)
+}
+
+/// Verifies that a version proposed by a counterparty during `ChanOpenTry`
+/// can be selected from, i.e. that the intersection with our own supported
+/// versions is non-empty.
+pub fn verify_proposed_version(
+    supported_versions: &[Version],
+    counterparty_proposed_versions: &[Version],
+) -> Result<(), ChannelError> {
+    pick_version(supported_versions, counterparty_proposed_versions).map(|_| ())
+}
+
+/// Verifies that the version acknowledged by the counterparty at
+/// `ChanOpenAck` is one this chain actually offered during `ChanOpenInit`,
+/// rejecting an unsolicited or unsupported version.
+pub fn verify_acknowledged_version(
+    acknowledged_version: &Version,
+    supported_versions: &[Version],
+) -> Result<(), ChannelError> {
+    if supported_versions.contains(acknowledged_version) {
+        Ok(())
+    } else {
+        Err(ChannelError::VersionNotSupported {
+            version: acknowledged_version.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pick_version_finds_common_version() {
+        let supported = vec![Version::ics20()];
+        let proposed = vec![Version::new("ics27-1".to_string()), Version::ics20()];
+        assert_eq!(pick_version(&supported, &proposed).unwrap(), Version::ics20());
+    }
+
+    #[test]
+    fn pick_version_rejects_disjoint_versions() {
+        let supported = vec![Version::ics20()];
+        let proposed = vec![Version::new("ics27-1".to_string())];
+        assert!(pick_version(&supported, &proposed).is_err());
+    }
+
+    #[test]
+    fn verify_acknowledged_version_rejects_unsolicited_version() {
+        let supported = vec![Version::ics20()];
+        let acknowledged = Version::new("v1.1.23-alpha".to_string());
+        assert!(verify_acknowledged_version(&acknowledged, &supported).is_err());
+    }
+
+    #[test]
+    fn verify_acknowledged_version_accepts_offered_version() {
+        let supported = vec![Version::ics20()];
+        assert!(verify_acknowledged_version(&Version::ics20(), &supported).is_ok());
+    }
+}
@@ -0,0 +1,144 @@
+//! Defines the commitment types used by ICS-23 proof verification:
+//! [`CommitmentProofBytes`], the opaque Merkle proof bytes attached to
+//! handshake and packet messages, and [`CommitmentPrefix`], the store prefix
+//! a chain applies to every IBC commitment path.
+
+use core::convert::TryFrom;
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use crate::prelude::*;
+
+/// An error raised while constructing a commitment type from raw bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// commitment proof bytes cannot be empty
+    EmptyCommitmentProof,
+    /// commitment prefix cannot be empty
+    EmptyCommitmentPrefix,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Error::EmptyCommitmentProof => write!(f, "commitment proof bytes cannot be empty"),
+            Error::EmptyCommitmentPrefix => write!(f, "commitment prefix cannot be empty"),
+        }
+    }
+}
+
+/// A wrapper around a vector of bytes representing a Merkle proof, carried on
+/// handshake and packet messages. Always non-empty: a structurally valid
+/// message can never carry a zero-length proof. No `Default` impl: an empty
+/// `CommitmentProofBytes` would violate that invariant, so the only way to
+/// construct one is the fallible `TryFrom<Vec<u8>>` below.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitmentProofBytes(Vec<u8>);
+
+impl CommitmentProofBytes {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl TryFrom<Vec<u8>> for CommitmentProofBytes {
+    type Error = Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        if bytes.is_empty() {
+            return Err(Error::EmptyCommitmentProof);
+        }
+        Ok(Self(bytes))
+    }
+}
+
+impl From<CommitmentProofBytes> for Vec<u8> {
+    fn from(proof: CommitmentProofBytes) -> Self {
+        proof.0
+    }
+}
+
+/// The Merkle root of a chain's state, committed to by its consensus state.
+/// No `Default` impl: an empty root isn't a meaningful chain state to
+/// verify proofs against, so construction always goes through
+/// `from_bytes`/`From<Vec<u8>>` with real root bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitmentRoot(Vec<u8>);
+
+impl CommitmentRoot {
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for CommitmentRoot {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+/// The prefix a chain prepends to every IBC commitment path before it is
+/// stored, e.g. the `"ibc"` store key on a Cosmos SDK chain. Always
+/// non-empty: an empty prefix would make every commitment path ambiguous.
+/// No `Default` impl: an empty `CommitmentPrefix` would violate that
+/// invariant, so the only way to construct one is the fallible
+/// `TryFrom<Vec<u8>>` below.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitmentPrefix(Vec<u8>);
+
+impl CommitmentPrefix {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl TryFrom<Vec<u8>> for CommitmentPrefix {
+    type Error = Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        if bytes.is_empty() {
+            return Err(Error::EmptyCommitmentPrefix);
+        }
+        Ok(Self(bytes))
+    }
+}
+
+impl From<CommitmentPrefix> for Vec<u8> {
+    fn from(prefix: CommitmentPrefix) -> Self {
+        prefix.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_commitment_proof_is_rejected() {
+        assert_eq!(
+            CommitmentProofBytes::try_from(Vec::new()).unwrap_err(),
+            Error::EmptyCommitmentProof
+        );
+    }
+
+    #[test]
+    fn empty_commitment_prefix_is_rejected() {
+        assert_eq!(
+            CommitmentPrefix::try_from(Vec::new()).unwrap_err(),
+            Error::EmptyCommitmentPrefix
+        );
+    }
+
+    #[test]
+    fn non_empty_commitment_proof_is_accepted() {
+        assert!(CommitmentProofBytes::try_from(vec![1, 2, 3]).is_ok());
+    }
+
+    #[test]
+    fn non_empty_commitment_prefix_is_accepted() {
+        assert!(CommitmentPrefix::try_from(b"ibc".to_vec()).is_ok());
+    }
+}
@@ -0,0 +1,120 @@
+//! Defines strongly-typed IBC commitment paths.
+//!
+//! Each `*Path` type renders, via `Display`, the IBC commitment-path string
+//! defined by ICS-24 for the value it keys. `ClientStatePath` and
+//! `ClientConsensusStatePath` are the keys the ics02 client contexts take.
+//! The connection and channel readers do not take `ConnectionPath` or
+//! `ChannelEndPath`: their getters are still keyed by loose identifier
+//! arguments (e.g. `ctx.connection_end(&msg.connection_id)` in
+//! `conn_open_confirm`).
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use crate::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::prelude::*;
+use crate::Height;
+
+/// path"clients/{client_id}/clientState"
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClientStatePath(pub ClientId);
+
+impl Display for ClientStatePath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "clients/{}/clientState", self.0)
+    }
+}
+
+/// path "clients/{client_id}/consensusStates/{revision_number}-{revision_height}"
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClientConsensusStatePath {
+    pub client_id: ClientId,
+    pub revision_number: u64,
+    pub revision_height: u64,
+}
+
+impl ClientConsensusStatePath {
+    pub fn new(client_id: &ClientId, height: &Height) -> Self {
+        Self {
+            client_id: client_id.clone(),
+            revision_number: height.revision_number(),
+            revision_height: height.revision_height(),
+        }
+    }
+}
+
+impl Display for ClientConsensusStatePath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "clients/{}/consensusStates/{}-{}",
+            self.client_id, self.revision_number, self.revision_height
+        )
+    }
+}
+
+/// path "connections/{connection_id}"
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConnectionPath(pub ConnectionId);
+
+impl ConnectionPath {
+    pub fn new(connection_id: &ConnectionId) -> Self {
+        Self(connection_id.clone())
+    }
+}
+
+impl Display for ConnectionPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "connections/{}", self.0)
+    }
+}
+
+/// path "channelEnds/ports/{port_id}/channels/{channel_id}"
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChannelEndPath(pub PortId, pub ChannelId);
+
+impl ChannelEndPath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> Self {
+        Self(port_id.clone(), channel_id.clone())
+    }
+}
+
+impl Display for ChannelEndPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "channelEnds/ports/{}/channels/{}", self.0, self.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::str::FromStr;
+
+    #[test]
+    fn connection_path_renders_ics24_string() {
+        let connection_id = ConnectionId::from_str("connection-0").unwrap();
+        let path = ConnectionPath::new(&connection_id);
+        assert_eq!(path.to_string(), "connections/connection-0");
+    }
+
+    #[test]
+    fn channel_end_path_renders_ics24_string() {
+        let port_id = PortId::from_str("transfer").unwrap();
+        let channel_id = ChannelId::from_str("channel-0").unwrap();
+        let path = ChannelEndPath::new(&port_id, &channel_id);
+        assert_eq!(
+            path.to_string(),
+            "channelEnds/ports/transfer/channels/channel-0"
+        );
+    }
+
+    #[test]
+    fn client_consensus_state_path_renders_ics24_string() {
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let height = Height::new(0, 10).unwrap();
+        let path = ClientConsensusStatePath::new(&client_id, &height);
+        assert_eq!(
+            path.to_string(),
+            "clients/07-tendermint-0/consensusStates/0-10"
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! Optional verification that a counterparty's client correctly tracks this
+//! chain's own consensus state, driven by the `host_consensus_state_proof`
+//! field on `MsgConnectionOpenAck` and the consensus height it carries.
+//!
+//! Reconstructing "what our own consensus state looked like at a past
+//! height" is chain-specific (a Tendermint host reconstructs it differently
+//! than, say, a Substrate host), so this is opt-in per host rather than a
+//! mandatory step of the connection handshake: some environments cannot
+//! reproduce their own historical consensus state at all.
+
+use ibc_core_host_types::error::HostError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+/// A host-specific payload proving that the counterparty's client tracks
+/// this chain's consensus state correctly. Modeled after non-Tendermint
+/// hosts (e.g. a Substrate chain) that must additionally supply the header
+/// and extrinsic proof used to derive the consensus state, rather than
+/// reading it directly off a local store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HostConsensusProof {
+    /// The host header the consensus state is derived from.
+    pub header: Any,
+    /// The state-transition extrinsic applied at that header.
+    pub extrinsic: Vec<u8>,
+    /// Proof that `extrinsic` was included at `header`.
+    pub extrinsic_proof: Vec<u8>,
+    /// A checksum identifying the runtime/config used to derive the
+    /// consensus state, so a verifier can detect a mismatched
+    /// reconstruction recipe.
+    pub checksum: Vec<u8>,
+}
+
+/// The consensus height `MsgConnectionOpenAck` carries the host consensus
+/// proof for, as a single typed value rather than a loose
+/// `(revision_number, revision_height)` pair. Kept local to this module
+/// (rather than reusing a shared `Height`) since `MsgConnectionOpenAck`'s
+/// defining file isn't in this tree to confirm which `Height` type it
+/// actually uses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConsensusHeight {
+    pub revision_number: u64,
+    pub revision_height: u64,
+}
+
+impl ConsensusHeight {
+    pub fn new(revision_number: u64, revision_height: u64) -> Self {
+        Self {
+            revision_number,
+            revision_height,
+        }
+    }
+}
+
+/// Host hook for verifying a counterparty's tracked view of this chain.
+///
+/// The default implementation is a no-op: hosts that want this additional
+/// check during `conn_open_ack` must opt in by overriding it, since
+/// reconstructing a host's own historical consensus state is chain-specific
+/// and not every host can do it.
+pub trait HostConsensusStateVerifier {
+    fn verify_host_consensus_state_proof(
+        &self,
+        _proof: &HostConsensusProof,
+        _consensus_height: ConsensusHeight,
+    ) -> Result<(), HostError> {
+        Ok(())
+    }
+}
+
+/// The call site a `ConnOpenAck` handler would use: runs the host's
+/// [`HostConsensusStateVerifier`] hook against `MsgConnectionOpenAck`'s
+/// optional `host_consensus_state_proof` field, skipping the check
+/// entirely when the counterparty didn't submit one (the hook is opt-in,
+/// not mandatory).
+///
+/// Neither `MsgConnectionOpenAck` nor a `conn_open_ack` handler exist yet
+/// in `ibc-core::ics03_connection` for this to be called from directly (the
+/// module has no other files to host them in this tree), so this is the
+/// closest reachable integration point until that handler is added.
+pub fn verify_counterparty_host_consensus_state(
+    ctx: &impl HostConsensusStateVerifier,
+    host_consensus_state_proof: Option<&HostConsensusProof>,
+    consensus_height: ConsensusHeight,
+) -> Result<(), HostError> {
+    let Some(proof) = host_consensus_state_proof else {
+        return Ok(());
+    };
+
+    ctx.verify_host_consensus_state_proof(proof, consensus_height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy_proof() -> HostConsensusProof {
+        HostConsensusProof {
+            header: Any {
+                type_url: "dummy".into(),
+                value: Vec::new(),
+            },
+            extrinsic: Vec::new(),
+            extrinsic_proof: Vec::new(),
+            checksum: Vec::new(),
+        }
+    }
+
+    struct NoopHost;
+    impl HostConsensusStateVerifier for NoopHost {}
+
+    // A host overriding `verify_host_consensus_state_proof` to reject a
+    // forged/divergent proof would do so by returning an `Err(HostError)`
+    // here instead of the default `Ok(())`; we don't construct a concrete
+    // `HostError` value in this test since its defining module isn't
+    // present in this tree to confirm a constructor against.
+
+    #[test]
+    fn skips_check_when_no_proof_was_submitted() {
+        assert!(
+            verify_counterparty_host_consensus_state(&NoopHost, None, ConsensusHeight::new(0, 10))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn default_hook_accepts_any_submitted_proof() {
+        let proof = dummy_proof();
+        assert!(verify_counterparty_host_consensus_state(
+            &NoopHost,
+            Some(&proof),
+            ConsensusHeight::new(0, 10)
+        )
+        .is_ok());
+    }
+}
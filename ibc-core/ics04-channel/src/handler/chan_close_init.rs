@@ -0,0 +1,191 @@
+//! Protocol logic specific to processing `MsgChannelCloseInit`.
+//!
+//! Mirrors the "validate then execute" split [`Router`]/[`Module`] are built
+//! for: the channel's bound module callback runs first, and only once it
+//! succeeds does this return the event a caller should emit. No channel
+//! state is mutated here - a caller wiring this into a full
+//! `ExecutionContext` commits the closed state itself after this returns
+//! `Ok`.
+
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::ChanCloseInit;
+use ibc_core_channel_types::msgs::chan_close_init::MsgChannelCloseInit;
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core_routing_types::router::Router;
+use ibc_primitives::prelude::*;
+
+/// The channel-end facts this handler needs that `MsgChannelCloseInit`
+/// itself doesn't carry: the counterparty's port/channel identity and the
+/// connection the channel runs over. A full modular `ChannelReader`/
+/// `ValidationContext` for `ics04_channel` isn't in this tree, so this is
+/// the narrowest read this handler can take instead of one.
+pub struct ChannelEndFacts {
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: ChannelId,
+    pub connection_id: ConnectionId,
+}
+
+/// Looks up the module bound to `msg.port_id_on_a`/`msg.chan_id_on_a` via
+/// [`Router::lookup_module_by_channel`], invokes its `on_chan_close_init`
+/// callback, and returns the event to emit once that callback succeeds.
+pub fn process(
+    router: &mut impl Router,
+    channel_end_facts: &ChannelEndFacts,
+    msg: &MsgChannelCloseInit,
+) -> Result<ChanCloseInit, ChannelError> {
+    let module_id = router
+        .lookup_module_by_channel(&msg.chan_id_on_a, &msg.port_id_on_a)
+        .ok_or_else(|| ChannelError::RouteNotFound {
+            port_id: msg.port_id_on_a.to_string(),
+            channel_id: msg.chan_id_on_a.to_string(),
+        })?;
+
+    let module = router
+        .route_mut(&module_id)
+        .ok_or_else(|| ChannelError::RouteNotFound {
+            port_id: msg.port_id_on_a.to_string(),
+            channel_id: msg.chan_id_on_a.to_string(),
+        })?;
+
+    module.on_chan_close_init(&msg.port_id_on_a, &msg.chan_id_on_a)?;
+
+    Ok(ChanCloseInit::new(
+        msg.port_id_on_a.clone(),
+        msg.chan_id_on_a.clone(),
+        channel_end_facts.counterparty_port_id.clone(),
+        channel_end_facts.counterparty_channel_id.clone(),
+        channel_end_facts.connection_id.clone(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+
+    use ibc_core_routing_types::module::{Module, ModuleId};
+
+    struct AcceptingModule;
+    impl Module for AcceptingModule {
+        fn on_chan_close_init(
+            &mut self,
+            _port_id: &PortId,
+            _channel_id: &ChannelId,
+        ) -> Result<(), ChannelError> {
+            Ok(())
+        }
+
+        fn on_chan_close_confirm(
+            &mut self,
+            _port_id: &PortId,
+            _channel_id: &ChannelId,
+        ) -> Result<(), ChannelError> {
+            Ok(())
+        }
+    }
+
+    struct RejectingModule;
+    impl Module for RejectingModule {
+        fn on_chan_close_init(
+            &mut self,
+            _port_id: &PortId,
+            _channel_id: &ChannelId,
+        ) -> Result<(), ChannelError> {
+            Err(ChannelError::InvalidSigner)
+        }
+
+        fn on_chan_close_confirm(
+            &mut self,
+            _port_id: &PortId,
+            _channel_id: &ChannelId,
+        ) -> Result<(), ChannelError> {
+            Ok(())
+        }
+    }
+
+    struct MockRouter {
+        bindings: BTreeMap<(PortId, ChannelId), ModuleId>,
+        modules: BTreeMap<ModuleId, Box<dyn Module>>,
+    }
+
+    impl Router for MockRouter {
+        fn route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
+            self.modules.get(module_id).map(|m| m.as_ref())
+        }
+
+        fn route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
+            self.modules.get_mut(module_id).map(|m| m.as_mut())
+        }
+
+        fn lookup_module_by_channel(
+            &self,
+            channel_id: &ChannelId,
+            port_id: &PortId,
+        ) -> Option<ModuleId> {
+            self.bindings
+                .get(&(port_id.clone(), channel_id.clone()))
+                .cloned()
+        }
+    }
+
+    fn msg() -> MsgChannelCloseInit {
+        MsgChannelCloseInit {
+            port_id_on_a: PortId::default(),
+            chan_id_on_a: ChannelId::default(),
+            signer: "signer".to_string().into(),
+        }
+    }
+
+    fn channel_end_facts() -> ChannelEndFacts {
+        ChannelEndFacts {
+            counterparty_port_id: "transfer".parse().unwrap(),
+            counterparty_channel_id: ChannelId::new(1),
+            connection_id: ConnectionId::new(0),
+        }
+    }
+
+    #[test]
+    fn invokes_callback_and_returns_event_on_success() {
+        let module_id = ModuleId::new("transfer".to_string());
+        let mut bindings = BTreeMap::new();
+        bindings.insert(
+            (PortId::default(), ChannelId::default()),
+            module_id.clone(),
+        );
+        let mut modules: BTreeMap<ModuleId, Box<dyn Module>> = BTreeMap::new();
+        modules.insert(module_id, Box::new(AcceptingModule));
+        let mut router = MockRouter { bindings, modules };
+
+        let event = process(&mut router, &channel_end_facts(), &msg()).unwrap();
+        assert_eq!(event.port_id(), &PortId::default());
+        assert_eq!(event.channel_id(), &ChannelId::default());
+    }
+
+    #[test]
+    fn propagates_callback_error_without_emitting_event() {
+        let module_id = ModuleId::new("transfer".to_string());
+        let mut bindings = BTreeMap::new();
+        bindings.insert(
+            (PortId::default(), ChannelId::default()),
+            module_id.clone(),
+        );
+        let mut modules: BTreeMap<ModuleId, Box<dyn Module>> = BTreeMap::new();
+        modules.insert(module_id, Box::new(RejectingModule));
+        let mut router = MockRouter { bindings, modules };
+
+        assert!(process(&mut router, &channel_end_facts(), &msg()).is_err());
+    }
+
+    #[test]
+    fn rejects_when_no_module_is_bound() {
+        let mut router = MockRouter {
+            bindings: BTreeMap::new(),
+            modules: BTreeMap::new(),
+        };
+
+        assert!(matches!(
+            process(&mut router, &channel_end_facts(), &msg()),
+            Err(ChannelError::RouteNotFound { .. })
+        ));
+    }
+}
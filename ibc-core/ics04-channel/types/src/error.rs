@@ -0,0 +1,33 @@
+//! Defines [`ChannelError`], the error type shared by channel message
+//! decoding, ABCI event (de)serialization, and handshake validation
+//! failures in this crate.
+
+use displaydoc::Display;
+
+use ibc_core_host_types::error::IdentifierError;
+use ibc_primitives::prelude::*;
+
+/// An error raised while decoding, validating, or converting channel
+/// handshake messages and events.
+#[derive(Debug, Display)]
+pub enum ChannelError {
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// invalid signer
+    InvalidSigner,
+    /// missing attribute key: `{key}`
+    MissingAttributeKey { key: String },
+    /// mismatched event type: expected `{expected}`, got `{actual}`
+    MismatchedEventType { expected: String, actual: String },
+    /// no module is bound to port `{port_id}` channel `{channel_id}`
+    RouteNotFound { port_id: String, channel_id: String },
+}
+
+impl From<IdentifierError> for ChannelError {
+    fn from(e: IdentifierError) -> Self {
+        Self::InvalidIdentifier(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChannelError {}
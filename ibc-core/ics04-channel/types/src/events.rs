@@ -0,0 +1,290 @@
+//! Typed IBC events emitted for the channel-close handshake.
+//!
+//! Each event carries the identifiers a relayer needs to act on the
+//! handshake step: the local `port_id`/`channel_id`, the counterparty's
+//! `port_id`/`channel_id`, and the `connection_id` the channel runs over.
+//! Reconstructing an event from raw ABCI output accepts either the
+//! canonical event-type string or a deprecated alias, so relayers built
+//! against the older type strings keep working.
+
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
+use tendermint::abci;
+
+use crate::error::ChannelError;
+
+const PORT_ID_ATTRIBUTE_KEY: &str = "port_id";
+const CHANNEL_ID_ATTRIBUTE_KEY: &str = "channel_id";
+const COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY: &str = "counterparty_port_id";
+const COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY: &str = "counterparty_channel_id";
+const CONNECTION_ID_ATTRIBUTE_KEY: &str = "connection_id";
+
+/// Canonical and deprecated ABCI event-type strings for `ChanCloseInit`.
+const CHAN_CLOSE_INIT_EVENT: &str = "channel_close_init";
+const CHAN_CLOSE_INIT_EVENT_LEGACY: &str = "close_init_channel";
+
+/// Canonical and deprecated ABCI event-type strings for `ChanCloseConfirm`.
+const CHAN_CLOSE_CONFIRM_EVENT: &str = "channel_close_confirm";
+const CHAN_CLOSE_CONFIRM_EVENT_LEGACY: &str = "close_confirm_channel";
+
+/// The identifiers common to both channel-close events.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ChannelCloseAttributes {
+    port_id: PortId,
+    channel_id: ChannelId,
+    counterparty_port_id: PortId,
+    counterparty_channel_id: ChannelId,
+    connection_id: ConnectionId,
+}
+
+impl From<ChannelCloseAttributes> for Vec<abci::EventAttribute> {
+    fn from(a: ChannelCloseAttributes) -> Self {
+        vec![
+            (PORT_ID_ATTRIBUTE_KEY, a.port_id.as_str()).into(),
+            (CHANNEL_ID_ATTRIBUTE_KEY, a.channel_id.as_str()).into(),
+            (
+                COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY,
+                a.counterparty_port_id.as_str(),
+            )
+                .into(),
+            (
+                COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY,
+                a.counterparty_channel_id.as_str(),
+            )
+                .into(),
+            (CONNECTION_ID_ATTRIBUTE_KEY, a.connection_id.as_str()).into(),
+        ]
+    }
+}
+
+fn attribute_str(event: &abci::Event, key: &str) -> Result<String, ChannelError> {
+    event
+        .attributes
+        .iter()
+        .find(|attr| attr.key_str().map(|k| k == key).unwrap_or(false))
+        .and_then(|attr| attr.value_str().ok())
+        .map(ToString::to_string)
+        .ok_or_else(|| ChannelError::MissingAttributeKey {
+            key: key.to_string(),
+        })
+}
+
+impl TryFrom<&abci::Event> for ChannelCloseAttributes {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: attribute_str(event, PORT_ID_ATTRIBUTE_KEY)?.parse()?,
+            channel_id: attribute_str(event, CHANNEL_ID_ATTRIBUTE_KEY)?.parse()?,
+            counterparty_port_id: attribute_str(event, COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY)?
+                .parse()?,
+            counterparty_channel_id: attribute_str(event, COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY)?
+                .parse()?,
+            connection_id: attribute_str(event, CONNECTION_ID_ATTRIBUTE_KEY)?.parse()?,
+        })
+    }
+}
+
+/// Signals that a `MsgChannelCloseInit` was processed for a channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChanCloseInit {
+    attributes: ChannelCloseAttributes,
+}
+
+impl ChanCloseInit {
+    pub fn new(
+        port_id: PortId,
+        channel_id: ChannelId,
+        counterparty_port_id: PortId,
+        counterparty_channel_id: ChannelId,
+        connection_id: ConnectionId,
+    ) -> Self {
+        Self {
+            attributes: ChannelCloseAttributes {
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                connection_id,
+            },
+        }
+    }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.attributes.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.attributes.channel_id
+    }
+
+    pub fn counterparty_port_id(&self) -> &PortId {
+        &self.attributes.counterparty_port_id
+    }
+
+    pub fn counterparty_channel_id(&self) -> &ChannelId {
+        &self.attributes.counterparty_channel_id
+    }
+
+    pub fn connection_id(&self) -> &ConnectionId {
+        &self.attributes.connection_id
+    }
+}
+
+impl From<ChanCloseInit> for abci::Event {
+    fn from(event: ChanCloseInit) -> Self {
+        abci::Event {
+            kind: CHAN_CLOSE_INIT_EVENT.to_string(),
+            attributes: event.attributes.into(),
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for ChanCloseInit {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        if event.kind != CHAN_CLOSE_INIT_EVENT && event.kind != CHAN_CLOSE_INIT_EVENT_LEGACY {
+            return Err(ChannelError::MismatchedEventType {
+                expected: CHAN_CLOSE_INIT_EVENT.to_string(),
+                actual: event.kind,
+            });
+        }
+        Ok(Self {
+            attributes: ChannelCloseAttributes::try_from(&event)?,
+        })
+    }
+}
+
+/// Signals that a `MsgChannelCloseConfirm` was processed for a channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChanCloseConfirm {
+    attributes: ChannelCloseAttributes,
+}
+
+impl ChanCloseConfirm {
+    pub fn new(
+        port_id: PortId,
+        channel_id: ChannelId,
+        counterparty_port_id: PortId,
+        counterparty_channel_id: ChannelId,
+        connection_id: ConnectionId,
+    ) -> Self {
+        Self {
+            attributes: ChannelCloseAttributes {
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                connection_id,
+            },
+        }
+    }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.attributes.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.attributes.channel_id
+    }
+
+    pub fn counterparty_port_id(&self) -> &PortId {
+        &self.attributes.counterparty_port_id
+    }
+
+    pub fn counterparty_channel_id(&self) -> &ChannelId {
+        &self.attributes.counterparty_channel_id
+    }
+
+    pub fn connection_id(&self) -> &ConnectionId {
+        &self.attributes.connection_id
+    }
+}
+
+impl From<ChanCloseConfirm> for abci::Event {
+    fn from(event: ChanCloseConfirm) -> Self {
+        abci::Event {
+            kind: CHAN_CLOSE_CONFIRM_EVENT.to_string(),
+            attributes: event.attributes.into(),
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for ChanCloseConfirm {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        if event.kind != CHAN_CLOSE_CONFIRM_EVENT && event.kind != CHAN_CLOSE_CONFIRM_EVENT_LEGACY
+        {
+            return Err(ChannelError::MismatchedEventType {
+                expected: CHAN_CLOSE_CONFIRM_EVENT.to_string(),
+                actual: event.kind,
+            });
+        }
+        Ok(Self {
+            attributes: ChannelCloseAttributes::try_from(&event)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy_attributes() -> ChannelCloseAttributes {
+        ChannelCloseAttributes {
+            port_id: "transfer".parse().unwrap(),
+            channel_id: ChannelId::new(0),
+            counterparty_port_id: "transfer".parse().unwrap(),
+            counterparty_channel_id: ChannelId::new(1),
+            connection_id: ConnectionId::new(0),
+        }
+    }
+
+    #[test]
+    fn chan_close_init_round_trips_through_abci_event() {
+        let attributes = dummy_attributes();
+        let event = ChanCloseInit {
+            attributes: attributes.clone(),
+        };
+        let abci_event = abci::Event::from(event);
+        let parsed = ChanCloseInit::try_from(abci_event).unwrap();
+        assert_eq!(parsed.attributes, attributes);
+    }
+
+    #[test]
+    fn chan_close_init_accepts_legacy_event_type_alias() {
+        let attributes = dummy_attributes();
+        let mut abci_event: abci::Event = ChanCloseInit {
+            attributes: attributes.clone(),
+        }
+        .into();
+        abci_event.kind = CHAN_CLOSE_INIT_EVENT_LEGACY.to_string();
+
+        let parsed = ChanCloseInit::try_from(abci_event).unwrap();
+        assert_eq!(parsed.attributes, attributes);
+    }
+
+    #[test]
+    fn chan_close_confirm_accepts_legacy_event_type_alias() {
+        let attributes = dummy_attributes();
+        let mut abci_event: abci::Event = ChanCloseConfirm {
+            attributes: attributes.clone(),
+        }
+        .into();
+        abci_event.kind = CHAN_CLOSE_CONFIRM_EVENT_LEGACY.to_string();
+
+        let parsed = ChanCloseConfirm::try_from(abci_event).unwrap();
+        assert_eq!(parsed.attributes, attributes);
+    }
+
+    #[test]
+    fn rejects_unrelated_event_type() {
+        let attributes = dummy_attributes();
+        let mut abci_event: abci::Event = ChanCloseInit { attributes }.into();
+        abci_event.kind = "channel_open_init".to_string();
+
+        assert!(ChanCloseInit::try_from(abci_event).is_err());
+    }
+}
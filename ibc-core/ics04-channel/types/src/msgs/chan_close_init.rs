@@ -12,6 +12,13 @@ pub const CHAN_CLOSE_INIT_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelClose
 /// Message definition for the first step in the channel close handshake (`ChanCloseInit` datagram).
 /// Per our convention, this message is sent to chain A.
 ///
+/// Handling this message first looks up the `ModuleId` bound to
+/// `port_id_on_a`/`chan_id_on_a` via `ics26_routing::router::Router`, then
+/// invokes that module's `on_chan_close_init` callback before any channel
+/// state is mutated, so a failing callback leaves no state changes. Once the
+/// callback succeeds, the handler returns a [`crate::events::ChanCloseInit`]
+/// event for the caller to emit.
+///
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
@@ -38,6 +45,10 @@ impl TryFrom<RawMsgChannelCloseInit> for MsgChannelCloseInit {
     type Error = ChannelError;
 
     fn try_from(raw_msg: RawMsgChannelCloseInit) -> Result<Self, Self::Error> {
+        #[cfg(feature = "bech32")]
+        ibc_core_host_types::signer::string_to_account(&raw_msg.signer)
+            .map_err(|_| ChannelError::InvalidSigner)?;
+
         Ok(MsgChannelCloseInit {
             port_id_on_a: raw_msg.port_id.parse()?,
             chan_id_on_a: raw_msg.channel_id.parse()?,
@@ -112,18 +123,34 @@ mod tests {
                 },
                 want_pass: true,
             },
+            Test {
+                name: "Good parameters, arbitrary ICS-024 identifier".to_string(),
+                raw: RawMsgChannelCloseInit {
+                    channel_id: "custom-channel-id".to_string(),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: true,
+            },
+            Test {
+                name: "Channel identifier at the 64-char upper bound".to_string(),
+                raw: RawMsgChannelCloseInit {
+                    channel_id: "a".repeat(64),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: true,
+            },
             Test {
                 name: "Bad channel, name too short".to_string(),
                 raw: RawMsgChannelCloseInit {
-                    channel_id: "chshort".to_string(),
+                    channel_id: "".to_string(),
                     ..default_raw_msg.clone()
                 },
                 want_pass: false,
             },
             Test {
-                name: "Bad channel, name too long".to_string(),
+                name: "Bad channel, name too long (over the 64-char upper bound)".to_string(),
                 raw: RawMsgChannelCloseInit {
-                    channel_id: "channel-128391283791827398127398791283912837918273981273987912839".to_string(),
+                    channel_id: "a".repeat(65),
                     ..default_raw_msg
                 },
                 want_pass: false,
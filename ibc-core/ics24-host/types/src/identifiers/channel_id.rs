@@ -0,0 +1,108 @@
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+use ibc_primitives::prelude::*;
+
+use crate::error::IdentifierError;
+use crate::validate::{validate_identifier_chars, validate_identifier_length};
+
+/// The default prefix used when a chain allocates a new channel identifier
+/// for itself, e.g. `channel-0`, `channel-1`, ...
+const CHANNEL_ID_PREFIX: &str = "channel";
+
+/// Defines the domain type for channel identifiers.
+///
+/// A `ChannelId` accepts any ICS-024-valid identifier of up to 64 bytes, not
+/// just the canonical `channel-<sequence>` form a chain produces for itself:
+/// ICS-024 places no restriction on the identifier's shape beyond its
+/// allowed character set and length.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChannelId(String);
+
+impl ChannelId {
+    /// Builds a new channel identifier in the canonical sequence form this
+    /// chain uses when it allocates a channel for itself, e.g. `channel-27`.
+    pub fn new(identifier: u64) -> Self {
+        Self(format!("{CHANNEL_ID_PREFIX}-{identifier}"))
+    }
+
+    /// Get a reference to the underlying string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// This implementation provides a `to_string` method.
+impl Display for ChannelId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Construct a channel identifier from a string, accepting any ICS-024
+/// identifier between 1 and 64 bytes from the allowed character set, rather
+/// than enforcing the `channel-<sequence>` shape.
+impl FromStr for ChannelId {
+    type Err = IdentifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        validate_identifier_chars(s)?;
+        validate_identifier_length(s, 1, 64)?;
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl Default for ChannelId {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channel_id_from_str_accepts_canonical_sequence_form() {
+        assert_eq!(
+            ChannelId::from_str("channel-34").unwrap(),
+            ChannelId::new(34)
+        );
+    }
+
+    #[test]
+    fn channel_id_from_str_accepts_arbitrary_ics24_identifiers() {
+        assert!(ChannelId::from_str("custom-channel-id").is_ok());
+    }
+
+    #[test]
+    fn channel_id_from_str_accepts_64_char_upper_bound() {
+        let id = "a".repeat(64);
+        assert!(ChannelId::from_str(&id).is_ok());
+    }
+
+    #[test]
+    fn channel_id_from_str_rejects_over_64_chars() {
+        let id = "a".repeat(65);
+        assert!(ChannelId::from_str(&id).is_err());
+    }
+
+    #[test]
+    fn channel_id_from_str_rejects_too_short() {
+        assert!(ChannelId::from_str("").is_err());
+    }
+}
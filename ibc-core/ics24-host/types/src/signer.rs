@@ -0,0 +1,75 @@
+//! Optional bech32 validation and typed account extraction for [`Signer`].
+//!
+//! The `signer` field on IBC messages is built from a blanket
+//! `raw_msg.signer.into()` that accepts any string, so a malformed address
+//! only fails much later on-chain. Enabling the `bech32` feature exposes
+//! conversion helpers between a bech32 string and a 20-byte [`AccountId`].
+//! `MsgChannelCloseInit` decoding calls [`string_to_account`] to validate
+//! its signer when its own crate's `bech32` feature is on; other messages do
+//! not validate their signer. With the feature off, the signer is a
+//! permissive string pass-through.
+
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+
+#[cfg(feature = "bech32")]
+use bech32::{FromBase32, ToBase32};
+#[cfg(feature = "bech32")]
+use tendermint::account::Id as AccountId;
+
+#[cfg(feature = "bech32")]
+use crate::error::IdentifierError;
+
+/// Converts an [`AccountId`] back into its bech32 string representation
+/// under the given human-readable prefix.
+#[cfg(feature = "bech32")]
+pub fn account_to_string(hrp: &str, account: &AccountId) -> Result<String, IdentifierError> {
+    bech32::encode(hrp, account.as_bytes().to_base32(), bech32::Variant::Bech32)
+        .map_err(|_| IdentifierError::FailedToConvertSignerToAccount)
+}
+
+/// Bech32-decodes `s`, returning the 20-byte [`AccountId`] it encodes.
+///
+/// Splits the HRP from the payload, converts the 5-bit groups back to bytes
+/// via [`FromBase32`], and constructs an [`AccountId`] from the resulting
+/// 20-byte payload.
+#[cfg(feature = "bech32")]
+pub fn string_to_account(s: &str) -> Result<AccountId, IdentifierError> {
+    let (_hrp, data, _variant) =
+        bech32::decode(s).map_err(|_| IdentifierError::FailedToConvertSignerToAccount)?;
+    let bytes =
+        Vec::<u8>::from_base32(&data).map_err(|_| IdentifierError::FailedToConvertSignerToAccount)?;
+    AccountId::try_from(bytes).map_err(|_| IdentifierError::FailedToConvertSignerToAccount)
+}
+
+/// Extension trait exposing a typed [`AccountId`] accessor on [`Signer`]
+/// when the `bech32` feature is enabled.
+#[cfg(feature = "bech32")]
+pub trait SignerAccountExt {
+    fn as_account_id(&self) -> Result<AccountId, IdentifierError>;
+}
+
+#[cfg(feature = "bech32")]
+impl SignerAccountExt for Signer {
+    fn as_account_id(&self) -> Result<AccountId, IdentifierError> {
+        string_to_account(self.as_ref())
+    }
+}
+
+#[cfg(all(test, feature = "bech32"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_bech32() {
+        let account = AccountId::try_from(vec![0u8; 20]).unwrap();
+        let encoded = account_to_string("cosmos", &account).unwrap();
+        let decoded = string_to_account(&encoded).unwrap();
+        assert_eq!(account, decoded);
+    }
+
+    #[test]
+    fn rejects_malformed_address() {
+        assert!(string_to_account("not-a-bech32-address").is_err());
+    }
+}
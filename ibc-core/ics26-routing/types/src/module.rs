@@ -0,0 +1,51 @@
+//! Defines [`ModuleId`] and the [`Module`] trait applications implement to
+//! receive ICS-26 callbacks for the channels they own.
+
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+
+/// Identifies an application module bound to one or more ports, e.g.
+/// `"transfer"` for ICS-20.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ModuleId(String);
+
+impl ModuleId {
+    pub fn new(s: String) -> Self {
+        Self(s)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl core::fmt::Display for ModuleId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The callbacks an application module implements so the core handlers can
+/// notify it of handshake and packet lifecycle events on channels it owns.
+///
+/// Only the channel-close callbacks are defined here; other callbacks
+/// (`on_chan_open_init`, `on_recv_packet`, etc.) live alongside their own
+/// handshake/packet subsystems.
+pub trait Module {
+    /// Called during `ChanCloseInit` validation/execution for a channel
+    /// owned by this module, before any channel state is mutated.
+    fn on_chan_close_init(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError>;
+
+    /// Called during `ChanCloseConfirm` validation/execution for a channel
+    /// owned by this module, before any channel state is mutated.
+    fn on_chan_close_confirm(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError>;
+}
@@ -0,0 +1,34 @@
+//! Defines the [`Router`] trait used to dispatch ICS-26 callbacks to the
+//! application module bound to a given channel.
+
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+
+use crate::module::{Module, ModuleId};
+
+/// Looks up and dispatches to the [`Module`] bound to a given [`ModuleId`].
+///
+/// Handlers first resolve the owning `ModuleId` via
+/// [`Router::lookup_module_by_channel`], then use `route`/`route_mut` to
+/// reach the module's callbacks, mirroring a "validate then execute" split:
+/// a failing callback is caught before any channel state is mutated.
+pub trait Router {
+    /// Returns a shared reference to the module bound to `module_id`, if any.
+    fn route(&self, module_id: &ModuleId) -> Option<&dyn Module>;
+
+    /// Returns a mutable reference to the module bound to `module_id`, if
+    /// any.
+    fn route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module>;
+
+    /// Returns whether a module is bound to `module_id`.
+    fn has_route(&self, module_id: &ModuleId) -> bool {
+        self.route(module_id).is_some()
+    }
+
+    /// Resolves the `ModuleId` that owns the given channel/port pair, e.g.
+    /// by looking up the port binding recorded when the channel was opened.
+    fn lookup_module_by_channel(
+        &self,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+    ) -> Option<ModuleId>;
+}
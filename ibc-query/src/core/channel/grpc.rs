@@ -0,0 +1,171 @@
+//! A blanket server-side implementation of the Cosmos `ibc.core.channel.v1.Query`
+//! gRPC service for any host whose context implements [`ValidationContext`] and
+//! [`QueryContext`].
+//!
+//! This lets a host that has already wired up those context traits expose
+//! standard IBC channel gRPC endpoints without re-implementing the proto
+//! glue for every request/response pair.
+
+use ibc::core::channel::types::channel::IdentifiedChannelEnd;
+use ibc::core::host::types::path::{ChannelEndPath, Path, SeqRecvPath};
+use ibc::core::host::ValidationContext;
+use ibc_proto::ibc::core::channel::v1::query_server::Query;
+use ibc_proto::ibc::core::channel::v1::{
+    QueryChannelRequest as RawQueryChannelRequest,
+    QueryChannelResponse as RawQueryChannelResponse,
+    QueryChannelsRequest as RawQueryChannelsRequest,
+    QueryChannelsResponse as RawQueryChannelsResponse,
+    QueryNextSequenceReceiveRequest as RawQueryNextSequenceReceiveRequest,
+    QueryNextSequenceReceiveResponse as RawQueryNextSequenceReceiveResponse,
+    QueryPacketCommitmentsRequest as RawQueryPacketCommitmentsRequest,
+    QueryPacketCommitmentsResponse as RawQueryPacketCommitmentsResponse,
+    QueryUnreceivedPacketsRequest as RawQueryUnreceivedPacketsRequest,
+    QueryUnreceivedPacketsResponse as RawQueryUnreceivedPacketsResponse,
+};
+use tonic::{Request, Response, Status};
+
+use crate::core::channel::types::request::{
+    QueryChannelRequest, QueryChannelsRequest, QueryNextSequenceReceiveRequest,
+    QueryPacketCommitmentsRequest, QueryUnreceivedPacketsRequest,
+};
+use crate::core::client::grpc::ProofProvider;
+use crate::core::connection::grpc::paginate;
+use crate::core::context::QueryContext;
+
+/// A tonic [`Query`] service implementation backed by any
+/// `I: ValidationContext + QueryContext + ProofProvider`.
+pub struct QueryService<I> {
+    ctx: I,
+}
+
+impl<I> QueryService<I> {
+    pub fn new(ctx: I) -> Self {
+        Self { ctx }
+    }
+}
+
+#[tonic::async_trait]
+impl<I> Query for QueryService<I>
+where
+    I: ValidationContext + QueryContext + ProofProvider + Send + Sync + 'static,
+{
+    async fn channel(
+        &self,
+        request: Request<RawQueryChannelRequest>,
+    ) -> Result<Response<RawQueryChannelResponse>, Status> {
+        let request = QueryChannelRequest::try_from(request.into_inner())
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
+        let channel_end = self
+            .ctx
+            .channel_end(&channel_end_path)
+            .map_err(|e| Status::not_found(e.to_string()))?;
+
+        let query_height = self.ctx.query_height();
+        let proof = self
+            .ctx
+            .get_proof(query_height, &Path::ChannelEnd(channel_end_path))
+            .ok_or_else(|| Status::internal("could not produce channel end proof"))?;
+
+        Ok(Response::new(RawQueryChannelResponse {
+            channel: Some(channel_end.into()),
+            proof,
+            proof_height: Some(query_height.into()),
+        }))
+    }
+
+    async fn channels(
+        &self,
+        request: Request<RawQueryChannelsRequest>,
+    ) -> Result<Response<RawQueryChannelsResponse>, Status> {
+        let request = QueryChannelsRequest::from(request.into_inner());
+
+        let channel_ends: Vec<IdentifiedChannelEnd> = self
+            .ctx
+            .channel_ends()
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let (page, pagination) = paginate(channel_ends, request.pagination.as_ref(), |ice| {
+            format!("{}/{}", ice.port_id, ice.channel_id).into_bytes()
+        });
+
+        Ok(Response::new(RawQueryChannelsResponse {
+            channels: page.into_iter().map(Into::into).collect(),
+            pagination: Some(pagination.into()),
+            height: Some(self.ctx.query_height().into()),
+        }))
+    }
+
+    async fn packet_commitments(
+        &self,
+        request: Request<RawQueryPacketCommitmentsRequest>,
+    ) -> Result<Response<RawQueryPacketCommitmentsResponse>, Status> {
+        let request = QueryPacketCommitmentsRequest::try_from(request.into_inner())
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
+        let packet_states = self
+            .ctx
+            .packet_commitments(&channel_end_path)
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let (page, pagination) = paginate(packet_states, request.pagination.as_ref(), |state| {
+            format!("{:020}", u64::from(state.seq)).into_bytes()
+        });
+
+        Ok(Response::new(RawQueryPacketCommitmentsResponse {
+            commitments: page.into_iter().map(Into::into).collect(),
+            pagination: Some(pagination.into()),
+            height: Some(self.ctx.query_height().into()),
+        }))
+    }
+
+    async fn unreceived_packets(
+        &self,
+        request: Request<RawQueryUnreceivedPacketsRequest>,
+    ) -> Result<Response<RawQueryUnreceivedPacketsResponse>, Status> {
+        let request = QueryUnreceivedPacketsRequest::try_from(request.into_inner())
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
+        let unreceived = self
+            .ctx
+            .unreceived_packets(
+                &channel_end_path,
+                request.packet_commitment_sequences.into_iter(),
+            )
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(Response::new(RawQueryUnreceivedPacketsResponse {
+            sequences: unreceived.into_iter().map(u64::from).collect(),
+            height: Some(self.ctx.query_height().into()),
+        }))
+    }
+
+    async fn next_sequence_receive(
+        &self,
+        request: Request<RawQueryNextSequenceReceiveRequest>,
+    ) -> Result<Response<RawQueryNextSequenceReceiveResponse>, Status> {
+        let request = QueryNextSequenceReceiveRequest::try_from(request.into_inner())
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let seq_recv_path = SeqRecvPath::new(&request.port_id, &request.channel_id);
+        let next_sequence_receive = self
+            .ctx
+            .get_next_sequence_recv(&seq_recv_path)
+            .map_err(|e| Status::not_found(e.to_string()))?;
+
+        let query_height = self.ctx.query_height();
+        let proof = self
+            .ctx
+            .get_proof(query_height, &Path::SeqRecv(seq_recv_path))
+            .ok_or_else(|| Status::internal("could not produce next sequence receive proof"))?;
+
+        Ok(Response::new(RawQueryNextSequenceReceiveResponse {
+            next_sequence_receive: next_sequence_receive.into(),
+            proof,
+            proof_height: Some(query_height.into()),
+        }))
+    }
+}
@@ -0,0 +1,112 @@
+//! Contains all the RPC method request domain types and their conversions to
+//! and from the corresponding gRPC proto types for the channel module.
+
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_proto::ibc::core::channel::v1::{
+    QueryChannelRequest as RawQueryChannelRequest,
+    QueryChannelsRequest as RawQueryChannelsRequest,
+    QueryNextSequenceReceiveRequest as RawQueryNextSequenceReceiveRequest,
+    QueryPacketCommitmentsRequest as RawQueryPacketCommitmentsRequest,
+    QueryUnreceivedPacketsRequest as RawQueryUnreceivedPacketsRequest,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::error::QueryError;
+use crate::types::PageRequest;
+
+/// Defines the RPC method request type for querying a channel end.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryChannelRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
+impl TryFrom<RawQueryChannelRequest> for QueryChannelRequest {
+    type Error = QueryError;
+
+    fn try_from(request: RawQueryChannelRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: request.port_id.parse()?,
+            channel_id: request.channel_id.parse()?,
+        })
+    }
+}
+
+/// Defines the RPC method request type for querying all channel ends.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryChannelsRequest {
+    pub pagination: Option<PageRequest>,
+}
+
+impl From<RawQueryChannelsRequest> for QueryChannelsRequest {
+    fn from(request: RawQueryChannelsRequest) -> Self {
+        Self {
+            pagination: request.pagination.map(|pagination| pagination.into()),
+        }
+    }
+}
+
+/// Defines the RPC method request type for querying the packet commitments
+/// of a channel.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryPacketCommitmentsRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub pagination: Option<PageRequest>,
+}
+
+impl TryFrom<RawQueryPacketCommitmentsRequest> for QueryPacketCommitmentsRequest {
+    type Error = QueryError;
+
+    fn try_from(request: RawQueryPacketCommitmentsRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: request.port_id.parse()?,
+            channel_id: request.channel_id.parse()?,
+            pagination: request.pagination.map(|pagination| pagination.into()),
+        })
+    }
+}
+
+/// Defines the RPC method request type for querying the unreceived packets
+/// of a channel.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryUnreceivedPacketsRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub packet_commitment_sequences: Vec<Sequence>,
+}
+
+impl TryFrom<RawQueryUnreceivedPacketsRequest> for QueryUnreceivedPacketsRequest {
+    type Error = QueryError;
+
+    fn try_from(request: RawQueryUnreceivedPacketsRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: request.port_id.parse()?,
+            channel_id: request.channel_id.parse()?,
+            packet_commitment_sequences: request
+                .packet_commitment_sequences
+                .into_iter()
+                .map(Sequence::from)
+                .collect(),
+        })
+    }
+}
+
+/// Defines the RPC method request type for querying the next packet receive
+/// sequence of a channel.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryNextSequenceReceiveRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
+impl TryFrom<RawQueryNextSequenceReceiveRequest> for QueryNextSequenceReceiveRequest {
+    type Error = QueryError;
+
+    fn try_from(request: RawQueryNextSequenceReceiveRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: request.port_id.parse()?,
+            channel_id: request.channel_id.parse()?,
+        })
+    }
+}
@@ -0,0 +1,183 @@
+//! A blanket server-side implementation of the Cosmos `ibc.core.client.v1.Query`
+//! gRPC service for any host whose context implements [`ClientListProvider`]
+//! (an extension of [`ClientValidationContext`]) and [`ProofProvider`].
+//!
+//! This lets a host that has already wired up those context traits expose
+//! standard IBC client gRPC endpoints without re-implementing the proto
+//! glue for every request/response pair.
+
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath, Path};
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::client::v1::query_server::Query;
+use ibc_proto::ibc::core::client::v1::{
+    IdentifiedClientState, QueryClientStateRequest as RawQueryClientStateRequest,
+    QueryClientStateResponse as RawQueryClientStateResponse,
+    QueryClientStatesRequest as RawQueryClientStatesRequest,
+    QueryClientStatesResponse as RawQueryClientStatesResponse,
+    QueryConsensusStateHeightsRequest as RawQueryConsensusStateHeightsRequest,
+    QueryConsensusStateHeightsResponse as RawQueryConsensusStateHeightsResponse,
+    QueryConsensusStateRequest as RawQueryConsensusStateRequest,
+    QueryConsensusStateResponse as RawQueryConsensusStateResponse,
+};
+use tonic::{Request, Response, Status};
+
+use crate::core::client::types::request::{
+    QueryClientStateRequest, QueryClientStatesRequest, QueryConsensusStateHeightsRequest,
+    QueryConsensusStateRequest,
+};
+use crate::core::connection::grpc::paginate;
+
+/// Supplies the pieces a context alone cannot: the current query height to
+/// prove against, and the Merkle proof for a given path at that height.
+///
+/// Implemented separately from [`ClientValidationContext`] so hosts that
+/// cannot (or choose not to) serve proofs can still serve unproven reads.
+pub trait ProofProvider {
+    fn query_height(&self) -> Height;
+    fn get_proof(&self, height: Height, path: &Path) -> Option<Vec<u8>>;
+}
+
+/// Extends [`ClientValidationContext`] with the listing queries `ClientStates`
+/// and `ConsensusStateHeights` need. Kept separate (rather than requiring the
+/// much broader [`QueryContext`](crate::core::context::QueryContext)) so a
+/// type already implementing the full host [`ValidationContext`](ibc::core::host::ValidationContext)
+/// doesn't hit an ambiguous-associated-type error from implementing both —
+/// hosts backed by a [`QueryContext`](crate::core::context::QueryContext) can
+/// satisfy this trait with a thin delegating impl instead.
+pub trait ClientListProvider: ClientValidationContext {
+    fn client_states(&self) -> Result<Vec<(ClientId, Self::AnyClientState)>, ContextError>;
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
+}
+
+/// A tonic [`Query`] service implementation backed by any
+/// `I: ClientListProvider + ProofProvider`.
+pub struct QueryService<I> {
+    ctx: I,
+}
+
+impl<I> QueryService<I> {
+    pub fn new(ctx: I) -> Self {
+        Self { ctx }
+    }
+}
+
+#[tonic::async_trait]
+impl<I> Query for QueryService<I>
+where
+    I: ClientListProvider + ProofProvider + Send + Sync + 'static,
+    I::AnyClientState: Into<Any>,
+    I::AnyConsensusState: Into<Any>,
+{
+    async fn client_state(
+        &self,
+        request: Request<RawQueryClientStateRequest>,
+    ) -> Result<Response<RawQueryClientStateResponse>, Status> {
+        let request = QueryClientStateRequest::try_from(request.into_inner())
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let client_state = self
+            .ctx
+            .client_state(&request.client_id)
+            .map_err(|e| Status::not_found(e.to_string()))?;
+
+        let query_height = self.ctx.query_height();
+        let proof = self
+            .ctx
+            .get_proof(
+                query_height,
+                &Path::ClientState(ClientStatePath::new(request.client_id)),
+            )
+            .ok_or_else(|| Status::internal("could not produce client state proof"))?;
+
+        Ok(Response::new(RawQueryClientStateResponse {
+            client_state: Some(client_state.into()),
+            proof,
+            proof_height: Some(query_height.into()),
+        }))
+    }
+
+    async fn client_states(
+        &self,
+        request: Request<RawQueryClientStatesRequest>,
+    ) -> Result<Response<RawQueryClientStatesResponse>, Status> {
+        let request = QueryClientStatesRequest::from(request.into_inner());
+
+        let client_states = self
+            .ctx
+            .client_states()
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let (page, pagination) = paginate(client_states, request.pagination.as_ref(), |(id, _)| {
+            id.to_string().into_bytes()
+        });
+
+        Ok(Response::new(RawQueryClientStatesResponse {
+            client_states: page
+                .into_iter()
+                .map(|(client_id, client_state)| IdentifiedClientState {
+                    client_id: client_id.to_string(),
+                    client_state: Some(client_state.into()),
+                })
+                .collect(),
+            pagination: Some(pagination.into()),
+        }))
+    }
+
+    async fn consensus_state(
+        &self,
+        request: Request<RawQueryConsensusStateRequest>,
+    ) -> Result<Response<RawQueryConsensusStateResponse>, Status> {
+        let request = QueryConsensusStateRequest::try_from(request.into_inner())
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let query_height = self.ctx.query_height();
+        let consensus_height = request.consensus_height.unwrap_or(query_height);
+        let path = ClientConsensusStatePath::new(
+            request.client_id.clone(),
+            consensus_height.revision_number(),
+            consensus_height.revision_height(),
+        );
+
+        let consensus_state = self
+            .ctx
+            .consensus_state(&path)
+            .map_err(|e| Status::not_found(e.to_string()))?;
+
+        let proof = self
+            .ctx
+            .get_proof(query_height, &Path::ClientConsensusState(path))
+            .ok_or_else(|| Status::internal("could not produce consensus state proof"))?;
+
+        Ok(Response::new(RawQueryConsensusStateResponse {
+            consensus_state: Some(consensus_state.into()),
+            proof,
+            proof_height: Some(query_height.into()),
+        }))
+    }
+
+    async fn consensus_state_heights(
+        &self,
+        request: Request<RawQueryConsensusStateHeightsRequest>,
+    ) -> Result<Response<RawQueryConsensusStateHeightsResponse>, Status> {
+        let request = QueryConsensusStateHeightsRequest::try_from(request.into_inner())
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let heights = self
+            .ctx
+            .consensus_state_heights(&request.client_id)
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let (page, pagination) = paginate(heights, request.pagination.as_ref(), |height| {
+            height.to_string().into_bytes()
+        });
+
+        Ok(Response::new(RawQueryConsensusStateHeightsResponse {
+            consensus_state_heights: page.into_iter().map(Into::into).collect(),
+            pagination: Some(pagination.into()),
+        }))
+    }
+}
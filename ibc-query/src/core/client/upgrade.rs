@@ -0,0 +1,345 @@
+//! Helpers for resolving the store paths used by a client's
+//! `verify_upgrade_and_update_state` entry point, keyed off the same
+//! `upgrade_height` carried by [`super::types::request::QueryUpgradedClientStateRequest`]
+//! and [`super::types::request::QueryUpgradedConsensusStateRequest`], plus
+//! the entry point itself.
+//!
+//! A client's `upgrade_path` is a `Vec<String>` prefix configured by the
+//! chain, e.g. `["upgrade"]` (single segment, the common case) or
+//! `["upgrade", "upgradedIBCState"]` (a two-segment path nesting the IBC
+//! module's own sub-store). Earlier code assumed a fixed shape and panicked
+//! on the other; these helpers handle both without panicking.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use ibc::core::client::types::error::UpgradeClientError;
+use ibc::core::client::types::Height;
+
+/// The configured prefix under which a chain publishes its upgraded client
+/// and consensus states ahead of a halt-and-restart upgrade.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradePath<'a>(&'a [String]);
+
+impl<'a> UpgradePath<'a> {
+    pub fn new(segments: &'a [String]) -> Self {
+        Self(segments)
+    }
+
+    /// Returns the store path at which the upgraded client state is expected
+    /// to have been committed for `upgrade_height`.
+    pub fn client_state_path(&self, upgrade_height: Height) -> Result<String, UpgradeClientError> {
+        self.resolve(upgrade_height, "upgradedClient")
+    }
+
+    /// Returns the store path at which the upgraded consensus state is
+    /// expected to have been committed for `upgrade_height`.
+    pub fn consensus_state_path(
+        &self,
+        upgrade_height: Height,
+    ) -> Result<String, UpgradeClientError> {
+        self.resolve(upgrade_height, "upgradedConsState")
+    }
+
+    fn resolve(
+        &self,
+        upgrade_height: Height,
+        leaf: &str,
+    ) -> Result<String, UpgradeClientError> {
+        match self.0 {
+            [store_key] => Ok(format!(
+                "{store_key}/{}/{leaf}",
+                upgrade_height.revision_height()
+            )),
+            [store_key, sub_store_key] => Ok(format!(
+                "{store_key}/{sub_store_key}/{}/{leaf}",
+                upgrade_height.revision_height()
+            )),
+            _ => Err(UpgradeClientError::InvalidUpgradePath {
+                description: format!(
+                    "expected a 1- or 2-element upgrade path, got {} segments",
+                    self.0.len()
+                ),
+            }),
+        }
+    }
+
+    /// Verifies that the counterparty actually committed
+    /// `upgraded_client_state_bytes`/`upgraded_consensus_state_bytes` at this
+    /// path's `client_state_path`/`consensus_state_path` for `upgrade_height`
+    /// against `root`, rejects an upgrade that changes fields `ctx` doesn't
+    /// allow an upgrade to touch, then persists both states through `ctx`.
+    ///
+    /// Proof/root verification is delegated to `ctx` rather than performed
+    /// here directly: the concrete commitment-proof format a host checks
+    /// against is store-specific (the same reasoning a host's
+    /// `HostConsensusStateVerifier` hook uses for its own historical
+    /// consensus state), and no shared `ValidationContext` proof-checking
+    /// entry point for upgrade-specific paths exists in this tree to call
+    /// through instead.
+    pub fn verify_upgrade_and_update_state(
+        &self,
+        upgrade_height: Height,
+        root: &[u8],
+        proof_upgrade_client: &[u8],
+        proof_upgrade_consensus_state: &[u8],
+        upgraded_client_state_bytes: &[u8],
+        upgraded_consensus_state_bytes: &[u8],
+        ctx: &mut impl UpgradedStateKeeper,
+    ) -> Result<(), UpgradeVerificationError> {
+        let client_state_path = self
+            .client_state_path(upgrade_height)
+            .map_err(UpgradeVerificationError::InvalidUpgradePath)?;
+        let consensus_state_path = self
+            .consensus_state_path(upgrade_height)
+            .map_err(UpgradeVerificationError::InvalidUpgradePath)?;
+
+        if !ctx.verify_membership(
+            root,
+            &client_state_path,
+            upgraded_client_state_bytes,
+            proof_upgrade_client,
+        ) {
+            return Err(UpgradeVerificationError::ProofVerificationFailed {
+                path: client_state_path,
+            });
+        }
+        if !ctx.verify_membership(
+            root,
+            &consensus_state_path,
+            upgraded_consensus_state_bytes,
+            proof_upgrade_consensus_state,
+        ) {
+            return Err(UpgradeVerificationError::ProofVerificationFailed {
+                path: consensus_state_path,
+            });
+        }
+
+        ctx.validate_upgrade(upgraded_client_state_bytes)?;
+
+        ctx.store_client_state(upgraded_client_state_bytes.to_vec());
+        ctx.store_consensus_state(upgrade_height, upgraded_consensus_state_bytes.to_vec());
+
+        Ok(())
+    }
+}
+
+/// Failure modes specific to verifying and applying a counterparty-committed
+/// upgrade. Kept separate from [`UpgradeClientError`] (whose variants this
+/// crate can't add to, since its defining module lives outside this tree)
+/// rather than force-fitting proof-verification and field-restriction
+/// failures into `InvalidUpgradePath`, which means something narrower.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UpgradeVerificationError {
+    InvalidUpgradePath(UpgradeClientError),
+    ProofVerificationFailed { path: String },
+    DisallowedFieldChanged { description: String },
+}
+
+/// Host hook invoked by [`UpgradePath::verify_upgrade_and_update_state`] to
+/// check a counterparty-committed upgrade proof and persist the upgraded
+/// states. Operates on still-encoded bytes rather than a typed `ClientState`/
+/// `ConsensusState`, since those concrete types live outside this tree.
+pub trait UpgradedStateKeeper {
+    /// Checks `(root, key, value)` against `proof`. The concrete commitment
+    /// proof format (e.g. an ICS23 `CommitmentProof`) is store-specific, so
+    /// this is left to the host rather than decoded here.
+    fn verify_membership(&self, root: &[u8], key: &str, value: &[u8], proof: &[u8]) -> bool;
+
+    /// Checks that `new_client_state_bytes` only changes the fields this
+    /// host allows an upgrade to change (e.g. `latest_height` advancing
+    /// while `chain_id` stays fixed). The default implementation accepts any
+    /// new state; a host that wants the restriction opts in by overriding
+    /// it, since which fields are frozen is host/client-type-specific.
+    fn validate_upgrade(
+        &self,
+        _new_client_state_bytes: &[u8],
+    ) -> Result<(), UpgradeVerificationError> {
+        Ok(())
+    }
+
+    /// Persists the upgraded client state.
+    fn store_client_state(&mut self, client_state_bytes: Vec<u8>);
+
+    /// Persists the upgraded consensus state at `upgrade_height`.
+    fn store_consensus_state(&mut self, upgrade_height: Height, consensus_state_bytes: Vec<u8>);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn height() -> Height {
+        Height::new(0, 100).unwrap()
+    }
+
+    #[test]
+    fn single_segment_path_does_not_panic() {
+        let segments = vec!["upgrade".to_string()];
+        let path = UpgradePath::new(&segments);
+        assert_eq!(
+            path.client_state_path(height()).unwrap(),
+            "upgrade/100/upgradedClient"
+        );
+    }
+
+    #[test]
+    fn two_segment_path_is_supported() {
+        let segments = vec!["upgrade".to_string(), "upgradedIBCState".to_string()];
+        let path = UpgradePath::new(&segments);
+        assert_eq!(
+            path.consensus_state_path(height()).unwrap(),
+            "upgrade/upgradedIBCState/100/upgradedConsState"
+        );
+    }
+
+    #[test]
+    fn empty_path_is_rejected_not_panicked() {
+        let segments: Vec<String> = vec![];
+        let path = UpgradePath::new(&segments);
+        assert!(path.client_state_path(height()).is_err());
+    }
+
+    #[test]
+    fn overly_long_path_is_rejected_not_panicked() {
+        let segments = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let path = UpgradePath::new(&segments);
+        assert!(path.client_state_path(height()).is_err());
+    }
+
+    struct MockKeeper {
+        committed: alloc::collections::BTreeMap<String, Vec<u8>>,
+        reject_upgrade: bool,
+        stored_client_state: Option<Vec<u8>>,
+        stored_consensus_state: Option<(Height, Vec<u8>)>,
+    }
+
+    impl MockKeeper {
+        fn with_committed(entries: &[(&str, &[u8])]) -> Self {
+            Self {
+                committed: entries
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_vec()))
+                    .collect(),
+                reject_upgrade: false,
+                stored_client_state: None,
+                stored_consensus_state: None,
+            }
+        }
+    }
+
+    impl UpgradedStateKeeper for MockKeeper {
+        fn verify_membership(&self, _root: &[u8], key: &str, value: &[u8], proof: &[u8]) -> bool {
+            proof == b"valid-proof" && self.committed.get(key).map(Vec::as_slice) == Some(value)
+        }
+
+        fn validate_upgrade(
+            &self,
+            _new_client_state_bytes: &[u8],
+        ) -> Result<(), UpgradeVerificationError> {
+            if self.reject_upgrade {
+                Err(UpgradeVerificationError::DisallowedFieldChanged {
+                    description: "chain_id must not change across an upgrade".to_string(),
+                })
+            } else {
+                Ok(())
+            }
+        }
+
+        fn store_client_state(&mut self, client_state_bytes: Vec<u8>) {
+            self.stored_client_state = Some(client_state_bytes);
+        }
+
+        fn store_consensus_state(&mut self, upgrade_height: Height, consensus_state_bytes: Vec<u8>) {
+            self.stored_consensus_state = Some((upgrade_height, consensus_state_bytes));
+        }
+    }
+
+    #[test]
+    fn commits_both_states_when_proofs_and_field_check_pass() {
+        let segments = vec!["upgrade".to_string()];
+        let path = UpgradePath::new(&segments);
+        let mut keeper = MockKeeper::with_committed(&[
+            ("upgrade/100/upgradedClient", b"new-client-state"),
+            ("upgrade/100/upgradedConsState", b"new-consensus-state"),
+        ]);
+
+        path.verify_upgrade_and_update_state(
+            height(),
+            b"root",
+            b"valid-proof",
+            b"valid-proof",
+            b"new-client-state",
+            b"new-consensus-state",
+            &mut keeper,
+        )
+        .unwrap();
+
+        assert_eq!(
+            keeper.stored_client_state.as_deref(),
+            Some(b"new-client-state".as_slice())
+        );
+        assert_eq!(
+            keeper.stored_consensus_state,
+            Some((height(), b"new-consensus-state".to_vec()))
+        );
+    }
+
+    #[test]
+    fn rejects_when_proof_does_not_match_committed_value() {
+        let segments = vec!["upgrade".to_string()];
+        let path = UpgradePath::new(&segments);
+        let mut keeper = MockKeeper::with_committed(&[
+            ("upgrade/100/upgradedClient", b"new-client-state"),
+            ("upgrade/100/upgradedConsState", b"new-consensus-state"),
+        ]);
+
+        let err = path
+            .verify_upgrade_and_update_state(
+                height(),
+                b"root",
+                b"valid-proof",
+                b"valid-proof",
+                b"forged-client-state",
+                b"new-consensus-state",
+                &mut keeper,
+            )
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            UpgradeVerificationError::ProofVerificationFailed { .. }
+        ));
+        assert!(keeper.stored_client_state.is_none());
+    }
+
+    #[test]
+    fn rejects_upgrade_that_changes_a_disallowed_field() {
+        let segments = vec!["upgrade".to_string()];
+        let path = UpgradePath::new(&segments);
+        let mut keeper = MockKeeper::with_committed(&[
+            ("upgrade/100/upgradedClient", b"new-client-state"),
+            ("upgrade/100/upgradedConsState", b"new-consensus-state"),
+        ]);
+        keeper.reject_upgrade = true;
+
+        let err = path
+            .verify_upgrade_and_update_state(
+                height(),
+                b"root",
+                b"valid-proof",
+                b"valid-proof",
+                b"new-client-state",
+                b"new-consensus-state",
+                &mut keeper,
+            )
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            UpgradeVerificationError::DisallowedFieldChanged { .. }
+        ));
+        assert!(keeper.stored_client_state.is_none());
+    }
+}
@@ -0,0 +1,178 @@
+//! A blanket server-side implementation of the Cosmos `ibc.core.connection.v1.Query`
+//! gRPC service for any host whose context implements [`ValidationContext`] and
+//! [`QueryContext`].
+//!
+//! This lets a host that has already wired up those context traits expose
+//! standard IBC connection gRPC endpoints without re-implementing the proto
+//! glue for every request/response pair.
+
+use ibc::core::connection::types::IdentifiedConnectionEnd;
+use ibc::core::host::types::path::{ClientConnectionPath, ConnectionPath, Path};
+use ibc::core::host::ValidationContext;
+use ibc_proto::ibc::core::connection::v1::query_server::Query;
+use ibc_proto::ibc::core::connection::v1::{
+    QueryClientConnectionsRequest as RawQueryClientConnectionsRequest,
+    QueryClientConnectionsResponse as RawQueryClientConnectionsResponse,
+    QueryConnectionRequest as RawQueryConnectionRequest,
+    QueryConnectionResponse as RawQueryConnectionResponse,
+    QueryConnectionsRequest as RawQueryConnectionsRequest,
+    QueryConnectionsResponse as RawQueryConnectionsResponse,
+};
+use tonic::{Request, Response, Status};
+
+use crate::core::client::grpc::ProofProvider;
+use crate::core::connection::types::request::{
+    QueryClientConnectionsRequest, QueryConnectionRequest, QueryConnectionsRequest,
+};
+use crate::core::context::QueryContext;
+use crate::types::{PageRequest, PageResponse};
+
+/// Slices `items` (already in store order) according to `pagination`,
+/// defaulting to the full list when the caller didn't ask for a page.
+/// `key_bytes` extracts the cursor `next_key` should carry for the first
+/// item past the returned page; a request carrying that cursor as its `key`
+/// starts its page at that item instead of at `offset`. `reverse` is not
+/// applied.
+///
+/// Shared by the other core query services (client, channel) since none of
+/// them can rely on [`QueryContext`]'s listing methods to iterate with a
+/// cursor themselves.
+pub(crate) fn paginate<T>(
+    mut items: Vec<T>,
+    pagination: Option<&PageRequest>,
+    key_bytes: impl Fn(&T) -> Vec<u8>,
+) -> (Vec<T>, PageResponse) {
+    let total = items.len() as u64;
+
+    let Some(pagination) = pagination else {
+        return (
+            items,
+            PageResponse {
+                next_key: Vec::new(),
+                total,
+            },
+        );
+    };
+
+    let start = if pagination.key.is_empty() {
+        (pagination.offset as usize).min(items.len())
+    } else {
+        items
+            .iter()
+            .position(|item| key_bytes(item) == pagination.key)
+            .unwrap_or(items.len())
+    };
+    let limit = if pagination.limit == 0 {
+        items.len()
+    } else {
+        pagination.limit as usize
+    };
+    let end = items.len().min(start.saturating_add(limit));
+
+    let next_key = items.get(end).map(&key_bytes).unwrap_or_default();
+    let page = items.drain(start..end).collect();
+
+    (
+        page,
+        PageResponse {
+            next_key,
+            total: if pagination.count_total { total } else { 0 },
+        },
+    )
+}
+
+/// A tonic [`Query`] service implementation backed by any
+/// `I: ValidationContext + QueryContext + ProofProvider`.
+pub struct QueryService<I> {
+    ctx: I,
+}
+
+impl<I> QueryService<I> {
+    pub fn new(ctx: I) -> Self {
+        Self { ctx }
+    }
+}
+
+#[tonic::async_trait]
+impl<I> Query for QueryService<I>
+where
+    I: ValidationContext + QueryContext + ProofProvider + Send + Sync + 'static,
+{
+    async fn connection(
+        &self,
+        request: Request<RawQueryConnectionRequest>,
+    ) -> Result<Response<RawQueryConnectionResponse>, Status> {
+        let request = QueryConnectionRequest::try_from(request.into_inner())
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let connection_end = self
+            .ctx
+            .connection_end(&request.connection_id)
+            .map_err(|e| Status::not_found(e.to_string()))?;
+
+        let query_height = self.ctx.query_height();
+        let proof = self
+            .ctx
+            .get_proof(
+                query_height,
+                &Path::Connection(ConnectionPath::new(&request.connection_id)),
+            )
+            .ok_or_else(|| Status::internal("could not produce connection end proof"))?;
+
+        Ok(Response::new(RawQueryConnectionResponse {
+            connection: Some(connection_end.into()),
+            proof,
+            proof_height: Some(query_height.into()),
+        }))
+    }
+
+    async fn connections(
+        &self,
+        request: Request<RawQueryConnectionsRequest>,
+    ) -> Result<Response<RawQueryConnectionsResponse>, Status> {
+        let request = QueryConnectionsRequest::from(request.into_inner());
+
+        let connection_ends: Vec<IdentifiedConnectionEnd> = self
+            .ctx
+            .connection_ends()
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let (page, pagination) = paginate(connection_ends, request.pagination.as_ref(), |ice| {
+            ice.connection_id.to_string().into_bytes()
+        });
+
+        Ok(Response::new(RawQueryConnectionsResponse {
+            connections: page.into_iter().map(Into::into).collect(),
+            pagination: Some(pagination.into()),
+            height: Some(self.ctx.query_height().into()),
+        }))
+    }
+
+    async fn client_connections(
+        &self,
+        request: Request<RawQueryClientConnectionsRequest>,
+    ) -> Result<Response<RawQueryClientConnectionsResponse>, Status> {
+        let request = QueryClientConnectionsRequest::try_from(request.into_inner())
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let connection_paths = self
+            .ctx
+            .client_connection_ends(&request.client_id)
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let query_height = self.ctx.query_height();
+        let proof = self
+            .ctx
+            .get_proof(
+                query_height,
+                &Path::ClientConnection(ClientConnectionPath::new(request.client_id)),
+            )
+            .ok_or_else(|| Status::internal("could not produce client connections proof"))?;
+
+        Ok(Response::new(RawQueryClientConnectionsResponse {
+            connection_paths: connection_paths.iter().map(ToString::to_string).collect(),
+            proof,
+            proof_height: Some(query_height.into()),
+        }))
+    }
+}
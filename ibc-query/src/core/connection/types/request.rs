@@ -0,0 +1,60 @@
+//! Contains all the RPC method request domain types and their conversions to
+//! and from the corresponding gRPC proto types for the connection module.
+
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc_proto::ibc::core::connection::v1::{
+    QueryClientConnectionsRequest as RawQueryClientConnectionsRequest,
+    QueryConnectionRequest as RawQueryConnectionRequest,
+    QueryConnectionsRequest as RawQueryConnectionsRequest,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::error::QueryError;
+use crate::types::PageRequest;
+
+/// Defines the RPC method request type for querying a connection end.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryConnectionRequest {
+    pub connection_id: ConnectionId,
+}
+
+impl TryFrom<RawQueryConnectionRequest> for QueryConnectionRequest {
+    type Error = QueryError;
+
+    fn try_from(request: RawQueryConnectionRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            connection_id: request.connection_id.parse()?,
+        })
+    }
+}
+
+/// Defines the RPC method request type for querying all connection ends.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryConnectionsRequest {
+    pub pagination: Option<PageRequest>,
+}
+
+impl From<RawQueryConnectionsRequest> for QueryConnectionsRequest {
+    fn from(request: RawQueryConnectionsRequest) -> Self {
+        Self {
+            pagination: request.pagination.map(|pagination| pagination.into()),
+        }
+    }
+}
+
+/// Defines the RPC method request type for querying the connection paths
+/// associated with a client.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryClientConnectionsRequest {
+    pub client_id: ClientId,
+}
+
+impl TryFrom<RawQueryClientConnectionsRequest> for QueryClientConnectionsRequest {
+    type Error = QueryError;
+
+    fn try_from(request: RawQueryClientConnectionsRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_id: request.client_id.parse()?,
+        })
+    }
+}
@@ -5,7 +5,7 @@ use core::time::Duration;
 
 use basecoin_store::context::ProvableStore;
 use basecoin_store::types::height::Height as StoreHeight;
-use ibc::core::channel::types::channel::{ChannelEnd, IdentifiedChannelEnd};
+use ibc::core::channel::types::channel::{ChannelEnd, IdentifiedChannelEnd, Order};
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::packet::{PacketState, Receipt};
@@ -29,8 +29,14 @@ use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::{Signer, Timestamp};
 use ibc::primitives::proto::Any;
 use ibc::primitives::ToVec;
+use ibc_proto::ibc::core::commitment::v1::MerkleProof;
 use ibc_query::core::context::{ProvableContext, QueryContext};
+use ibc_query::types::{PageRequest, PageResponse};
 
+use super::events::IbcEventLog;
+use super::merkle::VersionedMerkleTree;
+use super::pagination::{paginate, unbounded_page_request};
+use super::txn::PendingWriteSet;
 use super::types::MockGenericContext;
 use crate::hosts::{TestBlock, TestHeader, TestHost};
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
@@ -139,9 +145,11 @@ where
     }
 
     fn commitment_prefix(&self) -> CommitmentPrefix {
-        // this is prefix of ibc store
-        // using default, as in our mock context, we don't store any other data
-        CommitmentPrefix::default()
+        // The configured, non-empty store key (e.g. `"ibc"`) real chains
+        // prefix every IBC key with. Proofs from `get_proof` are single-layer
+        // proofs over the bare path key and do not include this prefix; see
+        // `path_key`.
+        self.commitment_prefix.clone()
     }
 
     fn connection_counter(&self) -> Result<u64, ContextError> {
@@ -300,12 +308,242 @@ where
     S: ProvableStore + Debug,
     H: TestHost,
 {
-    /// Returns the proof for the given [`Height`] and [`Path`]
+    /// Returns the proof for the given [`Height`] and [`Path`], encoded as
+    /// an `ibc_proto` [`MerkleProof`] wrapping the store's ics23
+    /// [`CommitmentProof`]. Our mock store holds only the IBC substore, so a
+    /// single layer is the whole proof; a host composing further app-level
+    /// stores would append their own [`CommitmentProof`] layers on top,
+    /// each keyed by its store's [`ValidationContext::commitment_prefix`].
+    ///
+    /// The Merkle tree itself (leaf/inner hashing, and snapshotting a root
+    /// per committed [`StoreHeight`] so it matches the `app_hash` a
+    /// counterparty verifies against) is ordinarily `basecoin_store`'s
+    /// responsibility: `ProvableStore::get_proof` already returns an
+    /// `ExistenceProof` for present keys and a `NonExistenceProof`
+    /// bracketing the lexicographic neighbors for absent ones, which the
+    /// wrapper below exposes as-is. For [`Path::ClientState`], a `None` from
+    /// the store falls back to [`Self::client_state_merkle_proof`], which
+    /// builds the same kind of proof independently of `basecoin_store`, over
+    /// [`super::merkle::VersionedMerkleTree`] — so a host that only tracks
+    /// client/consensus state through this context's own enumeration, and
+    /// not through `basecoin_store`, still gets a verifiable proof.
     fn get_proof(&self, height: Height, path: &Path) -> Option<Vec<u8>> {
-        self.ibc_store
-            .store
-            .get_proof(height.revision_height().into(), &path.to_string().into())
-            .map(|p| p.to_vec())
+        if let Some(proof) = self.get_merkle_proof(height, path) {
+            return Some(proof.to_vec());
+        }
+
+        let Path::ClientState(ClientStatePath(client_id)) = path else {
+            return None;
+        };
+        let (_root, commitment_proof) = self.client_state_merkle_proof(client_id).ok()??;
+        Some(
+            MerkleProof {
+                proofs: vec![commitment_proof],
+            }
+            .to_vec(),
+        )
+    }
+}
+
+impl<S, H> MockGenericContext<S, H>
+where
+    S: ProvableStore + Debug,
+    H: TestHost,
+{
+    /// The store key a proof is generated/verified against: `path` itself,
+    /// with no additional commitment-prefix segment spliced in.
+    ///
+    /// `connection_end_store`/`channel_end_store`/etc. (the typed
+    /// `basecoin_store` wrappers `store_connection`/`store_channel`/etc.
+    /// actually write through) key their writes by the bare path's `Display`
+    /// form; the commitment prefix is a property of the whole substore these
+    /// paths live under, not something spliced into each individual key. An
+    /// earlier version of this method prepended
+    /// [`ValidationContext::commitment_prefix`] here, which made
+    /// [`Self::get_merkle_proof`]/[`Self::merkle_key`] look up and verify
+    /// against a key the store never actually wrote to, breaking membership
+    /// proofs for every path. Shared by [`Self::get_merkle_proof`] (which
+    /// fetches the proof the store generated for this key) and
+    /// [`Self::merkle_key`] (which [`Self::verify_membership`] checks the
+    /// proof against), so the two always agree on what was actually proven.
+    fn path_key(&self, path: &Path) -> String {
+        path.to_string()
+    }
+
+    /// Wraps the store's ics23 [`CommitmentProof`] for `path` at `height`
+    /// into a [`MerkleProof`].
+    fn get_merkle_proof(&self, height: Height, path: &Path) -> Option<MerkleProof> {
+        let commitment_proof = self.ibc_store.store.get_proof(
+            height.revision_height().into(),
+            &self.path_key(path).into(),
+        )?;
+
+        Some(MerkleProof {
+            proofs: vec![commitment_proof],
+        })
+    }
+
+    /// The key `ics23::verify_membership`/`verify_non_membership` check the
+    /// proof against: the same key [`Self::get_merkle_proof`] fetched
+    /// the proof for.
+    fn merkle_key(&self, path: &Path) -> Vec<u8> {
+        self.path_key(path).into_bytes()
+    }
+
+    /// Builds a [`VersionedMerkleTree`] over every stored client state and
+    /// every stored consensus state of `client_id`, committed as version
+    /// `0`, then returns an existence proof for `client_id`'s client state
+    /// in that tree, alongside the tree's root.
+    ///
+    /// This generates the proof independently of `basecoin_store`
+    /// (`Self::get_merkle_proof` above still delegates to it), over the
+    /// leaf/inner hashing scheme `VersionedMerkleTree` implements directly.
+    /// It only covers client and consensus state paths, since those are the
+    /// only ones this context can enumerate through
+    /// [`QueryContext::client_states`]/[`QueryContext::consensus_states`]
+    /// without depending on `MockIbcStore`'s own internal field layout.
+    pub fn client_state_merkle_proof(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Option<(Vec<u8>, ics23::CommitmentProof)>, ContextError> {
+        let mut tree = VersionedMerkleTree::new();
+
+        for (id, client_state) in self.client_states()? {
+            let key = self.path_key(&ClientStatePath(id).into());
+            tree.insert(key.into_bytes(), client_state.to_vec());
+        }
+        for (height, consensus_state) in self.consensus_states(client_id)? {
+            let path = ClientConsensusStatePath {
+                client_id: client_id.clone(),
+                revision_number: height.revision_number(),
+                revision_height: height.revision_height(),
+            };
+            let key = self.path_key(&path.into());
+            tree.insert(key.into_bytes(), consensus_state.to_vec());
+        }
+        tree.commit(0);
+
+        let root = tree.root_at(0).expect("just committed version 0");
+        let key = self.path_key(&ClientStatePath(client_id.clone()).into());
+        Ok(tree
+            .prove_at(0, key.as_bytes())
+            .map(|proof| (root, proof)))
+    }
+
+    /// Verifies that `(path, value)` is present in the IBC store at
+    /// `height`, by checking the proof's leaf/inner hashes (per the IAVL
+    /// `LeafOp`/`InnerOp` spec) fold up to the `MerkleRoot` carried by the
+    /// host consensus state at that height.
+    pub fn verify_membership(
+        &self,
+        height: Height,
+        path: &Path,
+        value: Vec<u8>,
+    ) -> Result<(), ContextError> {
+        let merkle_proof = self
+            .get_merkle_proof(height, path)
+            .ok_or_else(|| ClientError::Other {
+                description: format!("no proof found for path `{path}` at height {height}"),
+            })?;
+        let commitment_proof =
+            merkle_proof
+                .proofs
+                .first()
+                .ok_or_else(|| ClientError::Other {
+                    description: "empty merkle proof".into(),
+                })?;
+
+        let root = self.host_consensus_state(&height)?.root().as_bytes().to_vec();
+        let key = self.merkle_key(path);
+
+        if !ics23::verify_membership::<ics23::HostFunctionsManager>(
+            commitment_proof,
+            &ics23::iavl_spec(),
+            &root,
+            &key,
+            &value,
+        ) {
+            return Err(ClientError::Other {
+                description: format!("membership verification failed for path `{path}`"),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that no value is stored for `path` in the IBC store at
+    /// `height`, by checking that the proof's neighboring existence proofs
+    /// bracket the absent key, per ICS23.
+    pub fn verify_non_membership(&self, height: Height, path: &Path) -> Result<(), ContextError> {
+        let merkle_proof = self
+            .get_merkle_proof(height, path)
+            .ok_or_else(|| ClientError::Other {
+                description: format!("no proof found for path `{path}` at height {height}"),
+            })?;
+        let commitment_proof =
+            merkle_proof
+                .proofs
+                .first()
+                .ok_or_else(|| ClientError::Other {
+                    description: "empty merkle proof".into(),
+                })?;
+
+        let root = self.host_consensus_state(&height)?.root().as_bytes().to_vec();
+        let key = self.merkle_key(path);
+
+        if !ics23::verify_non_membership::<ics23::HostFunctionsManager>(
+            commitment_proof,
+            &ics23::iavl_spec(),
+            &root,
+            &key,
+        ) {
+            return Err(ClientError::Other {
+                description: format!("non-membership verification failed for path `{path}`"),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Rebuilds a [`super::events::IbcEventLog`] from the flat `events`/
+    /// `logs` buffers, attributing every currently-buffered entry to the
+    /// current [`Self::host_height`].
+    ///
+    /// This is the closest real integration available in this tree:
+    /// `MockIbcStore`'s `events`/`logs` fields are `Mutex<Vec<IbcEvent>>`/
+    /// `Mutex<Vec<String>>` (fixed by `types.rs`, which isn't present here
+    /// to change), so they can't carry a height per entry, and no per-height
+    /// history survives across calls. What this does provide, for real, is
+    /// height-indexed querying of the *current* height's events/logs
+    /// through the actual [`IbcEventLog`] code path, rather than leaving it
+    /// reachable only from its own unit tests. The log is built with no
+    /// retention window, so nothing is pruned.
+    fn event_log(&self) -> IbcEventLog {
+        let height = self.host_height().map(|h| h.revision_height()).unwrap_or(0);
+        let mut log = IbcEventLog::new(None);
+        for event in self.ibc_store.events.lock().iter() {
+            log.record_event(height, event.clone());
+        }
+        for message in self.ibc_store.logs.lock().iter() {
+            log.record_log(height, message.clone());
+        }
+        log
+    }
+
+    /// Every currently-buffered [`IbcEvent`] attributed to `height`, via
+    /// [`Self::event_log`]. Empty for any height other than the current one,
+    /// since the backing buffers retain no per-event height.
+    pub fn events_at(&self, height: Height) -> Vec<IbcEvent> {
+        self.event_log().events_at(height.revision_height()).to_vec()
+    }
+
+    /// Every currently-buffered log line attributed to `height`, via
+    /// [`Self::event_log`]. Empty for any height other than the current one,
+    /// since the backing buffers retain no per-event height.
+    pub fn logs_at(&self, height: Height) -> Vec<String> {
+        self.event_log().logs_at(height.revision_height()).to_vec()
     }
 }
 
@@ -317,9 +555,205 @@ where
 {
     /// Returns the list of all client states.
     fn client_states(&self) -> Result<Vec<(ClientId, Self::AnyClientState)>, ContextError> {
-        let path = "clients".to_owned().into();
+        Ok(self
+            .client_states_paginated(&unbounded_page_request())?
+            .0)
+    }
+
+    /// Returns the list of all consensus states of the given client.
+    fn consensus_states(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<(Height, Self::AnyConsensusState)>, ContextError> {
+        Ok(self
+            .consensus_states_paginated(client_id, &unbounded_page_request())?
+            .0)
+    }
+
+    /// Returns the list of heights at which the consensus state of the given client was updated.
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        let path = format!("clients/{}/consensusStates", client_id)
+            .try_into()
+            .map_err(|_| ClientError::Other {
+                description: "Invalid consensus state path".into(),
+            })?;
 
         self.ibc_store
+            .consensus_state_store
+            .get_keys(&path)
+            .into_iter()
+            .flat_map(|path| {
+                if let Ok(Path::ClientConsensusState(consensus_path)) = path.try_into() {
+                    Some(consensus_path)
+                } else {
+                    None
+                }
+            })
+            .map(|consensus_path| {
+                Ok(Height::new(
+                    consensus_path.revision_number,
+                    consensus_path.revision_height,
+                )?)
+            })
+            .collect::<Result<Vec<_>, _>>()
+    }
+
+    /// Connections queries all the IBC connections of a chain.
+    fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError> {
+        Ok(self
+            .connection_ends_paginated(&unbounded_page_request())?
+            .0)
+    }
+
+    /// ClientConnections queries all the connection paths associated with a client.
+    fn client_connection_ends(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<ConnectionId>, ContextError> {
+        let client_connection_path = ClientConnectionPath::new(client_id.clone());
+
+        Ok(self
+            .ibc_store
+            .connection_ids_store
+            .get(StoreHeight::Pending, &client_connection_path)
+            .unwrap_or_default())
+    }
+
+    /// Channels queries all the IBC channels of a chain.
+    fn channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, ContextError> {
+        Ok(self.channel_ends_paginated(&unbounded_page_request())?.0)
+    }
+
+    /// PacketCommitments returns all the packet commitments associated with a channel.
+    fn packet_commitments(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<PacketState>, ContextError> {
+        Ok(self
+            .packet_commitments_paginated(channel_end_path, &unbounded_page_request())?
+            .0)
+    }
+
+    /// PacketAcknowledgements returns all the packet acknowledgements associated with a channel.
+    /// Returns all the packet acknowledgements if sequences is empty.
+    fn packet_acknowledgements(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequences: impl ExactSizeIterator<Item = Sequence>,
+    ) -> Result<Vec<PacketState>, ContextError> {
+        Ok(self
+            .packet_acknowledgements_paginated(
+                channel_end_path,
+                sequences,
+                &unbounded_page_request(),
+            )?
+            .0)
+    }
+
+    /// UnreceivedPackets returns all the unreceived IBC packets associated with
+    /// a channel and sequences.
+    fn unreceived_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequences: impl ExactSizeIterator<Item = Sequence>,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        let channel_end = self.channel_end(channel_end_path)?;
+
+        if channel_end.ordering() == &Order::Ordered {
+            // Ordered channels don't write receipts: a sequence is received
+            // iff it is strictly below the next expected receive sequence.
+            let next_seq_recv = self.get_next_sequence_recv(&SeqRecvPath::new(
+                &channel_end_path.0,
+                &channel_end_path.1,
+            ))?;
+
+            return Ok(sequences
+                .into_iter()
+                .filter(|seq| *seq >= next_seq_recv)
+                .collect());
+        }
+
+        Ok(sequences
+            .into_iter()
+            .map(|seq| ReceiptPath::new(&channel_end_path.0, &channel_end_path.1, seq))
+            .filter(|receipt_path| {
+                self.ibc_store
+                    .packet_receipt_store
+                    .get(StoreHeight::Pending, receipt_path)
+                    .is_none()
+            })
+            .map(|receipts_path| receipts_path.sequence)
+            .collect())
+    }
+
+    /// UnreceivedAcks returns all the unreceived IBC acknowledgements associated with a channel and sequences.
+    /// Returns all the unreceived acks if sequences is empty.
+    fn unreceived_acks(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequences: impl ExactSizeIterator<Item = Sequence>,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        let collected_paths: Vec<_> = if sequences.len() == 0 {
+            // if sequences is empty, return all the acks
+            let commitment_path_prefix = format!(
+                "commitments/ports/{}/channels/{}/sequences",
+                channel_end_path.0, channel_end_path.1
+            )
+            .try_into()
+            .map_err(|_| PacketError::Other {
+                description: "Invalid commitment path".into(),
+            })?;
+
+            self.ibc_store
+                .packet_commitment_store
+                .get_keys(&commitment_path_prefix)
+                .into_iter()
+                .flat_map(|path| {
+                    if let Ok(Path::Commitment(commitment_path)) = path.try_into() {
+                        Some(commitment_path)
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        } else {
+            sequences
+                .into_iter()
+                .map(|seq| CommitmentPath::new(&channel_end_path.0, &channel_end_path.1, seq))
+                .collect()
+        };
+
+        Ok(collected_paths
+            .into_iter()
+            .filter(|commitment_path: &CommitmentPath| -> bool {
+                self.ibc_store
+                    .packet_commitment_store
+                    .get(StoreHeight::Pending, commitment_path)
+                    .is_some()
+            })
+            .map(|commitment_path| commitment_path.sequence)
+            .collect())
+    }
+}
+
+/// Paginated counterparts of the [`QueryContext`] listing methods, mirroring
+/// the `PageRequest`/`PageResponse` convention the real gRPC query services
+/// use. Each non-paginated method above reuses its variant here with an
+/// [`unbounded_page_request`], so the two never drift apart.
+impl<S, H> MockGenericContext<S, H>
+where
+    S: ProvableStore + Debug,
+    H: TestHost,
+{
+    /// Paginated variant of [`QueryContext::client_states`].
+    pub fn client_states_paginated(
+        &self,
+        request: &PageRequest,
+    ) -> Result<(Vec<(ClientId, AnyClientState)>, PageResponse), ContextError> {
+        let path = "clients".to_owned().into();
+
+        let client_states: Vec<_> = self
+            .ibc_store
             .client_state_store
             .get_keys(&path)
             .into_iter()
@@ -340,21 +774,27 @@ where
                     })?;
                 Ok((client_state_path.0, client_state))
             })
-            .collect()
+            .collect::<Result<_, ContextError>>()?;
+
+        Ok(paginate(client_states, request, |(client_id, _)| {
+            client_id.to_string().into_bytes()
+        }))
     }
 
-    /// Returns the list of all consensus states of the given client.
-    fn consensus_states(
+    /// Paginated variant of [`QueryContext::consensus_states`].
+    pub fn consensus_states_paginated(
         &self,
         client_id: &ClientId,
-    ) -> Result<Vec<(Height, Self::AnyConsensusState)>, ContextError> {
+        request: &PageRequest,
+    ) -> Result<(Vec<(Height, AnyConsensusState)>, PageResponse), ContextError> {
         let path = format!("clients/{}/consensusStates", client_id)
             .try_into()
             .map_err(|_| ClientError::Other {
                 description: "Invalid consensus state path".into(),
             })?;
 
-        self.ibc_store
+        let consensus_states: Vec<_> = self
+            .ibc_store
             .consensus_state_store
             .get_keys(&path)
             .into_iter()
@@ -382,42 +822,22 @@ where
                     })?;
                 Ok((height, client_state))
             })
-            .collect()
-    }
+            .collect::<Result<_, ContextError>>()?;
 
-    /// Returns the list of heights at which the consensus state of the given client was updated.
-    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
-        let path = format!("clients/{}/consensusStates", client_id)
-            .try_into()
-            .map_err(|_| ClientError::Other {
-                description: "Invalid consensus state path".into(),
-            })?;
-
-        self.ibc_store
-            .consensus_state_store
-            .get_keys(&path)
-            .into_iter()
-            .flat_map(|path| {
-                if let Ok(Path::ClientConsensusState(consensus_path)) = path.try_into() {
-                    Some(consensus_path)
-                } else {
-                    None
-                }
-            })
-            .map(|consensus_path| {
-                Ok(Height::new(
-                    consensus_path.revision_number,
-                    consensus_path.revision_height,
-                )?)
-            })
-            .collect::<Result<Vec<_>, _>>()
+        Ok(paginate(consensus_states, request, |(height, _)| {
+            height.to_string().into_bytes()
+        }))
     }
 
-    /// Connections queries all the IBC connections of a chain.
-    fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError> {
+    /// Paginated variant of [`QueryContext::connection_ends`].
+    pub fn connection_ends_paginated(
+        &self,
+        request: &PageRequest,
+    ) -> Result<(Vec<IdentifiedConnectionEnd>, PageResponse), ContextError> {
         let path = "connections".to_owned().into();
 
-        self.ibc_store
+        let connection_ends: Vec<_> = self
+            .ibc_store
             .connection_end_store
             .get_keys(&path)
             .into_iter()
@@ -441,28 +861,22 @@ where
                     connection_end,
                 })
             })
-            .collect()
-    }
-
-    /// ClientConnections queries all the connection paths associated with a client.
-    fn client_connection_ends(
-        &self,
-        client_id: &ClientId,
-    ) -> Result<Vec<ConnectionId>, ContextError> {
-        let client_connection_path = ClientConnectionPath::new(client_id.clone());
+            .collect::<Result<_, ContextError>>()?;
 
-        Ok(self
-            .ibc_store
-            .connection_ids_store
-            .get(StoreHeight::Pending, &client_connection_path)
-            .unwrap_or_default())
+        Ok(paginate(connection_ends, request, |identified| {
+            identified.connection_id.to_string().into_bytes()
+        }))
     }
 
-    /// Channels queries all the IBC channels of a chain.
-    fn channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, ContextError> {
+    /// Paginated variant of [`QueryContext::channel_ends`].
+    pub fn channel_ends_paginated(
+        &self,
+        request: &PageRequest,
+    ) -> Result<(Vec<IdentifiedChannelEnd>, PageResponse), ContextError> {
         let path = "channelEnds".to_owned().into();
 
-        self.ibc_store
+        let channel_ends: Vec<_> = self
+            .ibc_store
             .channel_end_store
             .get_keys(&path)
             .into_iter()
@@ -488,14 +902,19 @@ where
                     channel_end,
                 })
             })
-            .collect()
+            .collect::<Result<_, ContextError>>()?;
+
+        Ok(paginate(channel_ends, request, |identified| {
+            format!("{}/{}", identified.port_id, identified.channel_id).into_bytes()
+        }))
     }
 
-    /// PacketCommitments returns all the packet commitments associated with a channel.
-    fn packet_commitments(
+    /// Paginated variant of [`QueryContext::packet_commitments`].
+    pub fn packet_commitments_paginated(
         &self,
         channel_end_path: &ChannelEndPath,
-    ) -> Result<Vec<PacketState>, ContextError> {
+        request: &PageRequest,
+    ) -> Result<(Vec<PacketState>, PageResponse), ContextError> {
         let path = format!(
             "commitments/ports/{}/channels/{}/sequences",
             channel_end_path.0, channel_end_path.1
@@ -505,7 +924,8 @@ where
             description: "Invalid commitment path".into(),
         })?;
 
-        self.ibc_store
+        let packet_commitments: Vec<_> = self
+            .ibc_store
             .packet_commitment_store
             .get_keys(&path)
             .into_iter()
@@ -531,16 +951,20 @@ where
                         data: packet.as_ref().into(),
                     })
             })
-            .collect::<Result<Vec<_>, _>>()
+            .collect::<Result<_, ContextError>>()?;
+
+        Ok(paginate(packet_commitments, request, |packet_state| {
+            format!("{:020}", u64::from(packet_state.seq)).into_bytes()
+        }))
     }
 
-    /// PacketAcknowledgements returns all the packet acknowledgements associated with a channel.
-    /// Returns all the packet acknowledgements if sequences is empty.
-    fn packet_acknowledgements(
+    /// Paginated variant of [`QueryContext::packet_acknowledgements`].
+    pub fn packet_acknowledgements_paginated(
         &self,
         channel_end_path: &ChannelEndPath,
         sequences: impl ExactSizeIterator<Item = Sequence>,
-    ) -> Result<Vec<PacketState>, ContextError> {
+        request: &PageRequest,
+    ) -> Result<(Vec<PacketState>, PageResponse), ContextError> {
         let collected_paths: Vec<_> = if sequences.len() == 0 {
             // if sequences is empty, return all the acks
             let ack_path_prefix = format!(
@@ -571,7 +995,7 @@ where
                 .collect()
         };
 
-        collected_paths
+        let packet_acks: Vec<_> = collected_paths
             .into_iter()
             .filter(|ack_path| {
                 self.ibc_store
@@ -588,83 +1012,30 @@ where
                         data: packet.as_ref().into(),
                     })
             })
-            .collect::<Result<Vec<_>, _>>()
-    }
-
-    /// UnreceivedPackets returns all the unreceived IBC packets associated with
-    /// a channel and sequences.
-    fn unreceived_packets(
-        &self,
-        channel_end_path: &ChannelEndPath,
-        sequences: impl ExactSizeIterator<Item = Sequence>,
-    ) -> Result<Vec<Sequence>, ContextError> {
-        // QUESTION. Currently only works for unordered channels; ordered channels
-        // don't use receipts. However, ibc-go does it this way. Investigate if
-        // this query only ever makes sense on unordered channels.
-
-        Ok(sequences
-            .into_iter()
-            .map(|seq| ReceiptPath::new(&channel_end_path.0, &channel_end_path.1, seq))
-            .filter(|receipt_path| {
-                self.ibc_store
-                    .packet_receipt_store
-                    .get(StoreHeight::Pending, receipt_path)
-                    .is_none()
-            })
-            .map(|receipts_path| receipts_path.sequence)
-            .collect())
-    }
+            .collect::<Result<_, ContextError>>()?;
 
-    /// UnreceivedAcks returns all the unreceived IBC acknowledgements associated with a channel and sequences.
-    /// Returns all the unreceived acks if sequences is empty.
-    fn unreceived_acks(
-        &self,
-        channel_end_path: &ChannelEndPath,
-        sequences: impl ExactSizeIterator<Item = Sequence>,
-    ) -> Result<Vec<Sequence>, ContextError> {
-        let collected_paths: Vec<_> = if sequences.len() == 0 {
-            // if sequences is empty, return all the acks
-            let commitment_path_prefix = format!(
-                "commitments/ports/{}/channels/{}/sequences",
-                channel_end_path.0, channel_end_path.1
-            )
-            .try_into()
-            .map_err(|_| PacketError::Other {
-                description: "Invalid commitment path".into(),
-            })?;
-
-            self.ibc_store
-                .packet_commitment_store
-                .get_keys(&commitment_path_prefix)
-                .into_iter()
-                .flat_map(|path| {
-                    if let Ok(Path::Commitment(commitment_path)) = path.try_into() {
-                        Some(commitment_path)
-                    } else {
-                        None
-                    }
-                })
-                .collect()
-        } else {
-            sequences
-                .into_iter()
-                .map(|seq| CommitmentPath::new(&channel_end_path.0, &channel_end_path.1, seq))
-                .collect()
-        };
-
-        Ok(collected_paths
-            .into_iter()
-            .filter(|commitment_path: &CommitmentPath| -> bool {
-                self.ibc_store
-                    .packet_commitment_store
-                    .get(StoreHeight::Pending, commitment_path)
-                    .is_some()
-            })
-            .map(|commitment_path| commitment_path.sequence)
-            .collect())
+        Ok(paginate(packet_acks, request, |packet_state| {
+            format!("{:020}", u64::from(packet_state.seq)).into_bytes()
+        }))
     }
 }
 
+// `store_connection`/`store_channel` below buffer their write through a
+// `PendingWriteSet` and apply it via `PendingWriteSet::apply_with`, for
+// real, rather than writing `self.ibc_store.*_store.set` directly. The
+// other methods here don't, because without a `types.rs` field to hold a
+// buffer that outlives one call, there's nothing to buffer *across* — a
+// `PendingWriteSet` built and drained within a single method call has
+// commit/rollback shape but not cross-call atomicity spanning a whole
+// handler, so extending this to every `store_*`/`delete_*`/
+// `increase_*_counter` method here would be the same single-op pattern
+// repeated without adding anything. Likewise, nothing here evicts consensus
+// states or stale packet commitment/receipt/ack entries past a retention
+// window: `super::txn::RetentionPolicy` needs a height-keyed store it can
+// hold a reference to across heights, which again needs a field this tree's
+// `MockIbcStore` doesn't have room for. `super::txn` implements and tests
+// both pieces standalone so a host with that file can wire them in with
+// real cross-call state instead of guessing at the shape from scratch.
 impl<S, H> ExecutionContext for MockGenericContext<S, H>
 where
     S: ProvableStore + Debug,
@@ -692,18 +1063,35 @@ where
         Ok(())
     }
 
-    /// Stores the given connection_end at path
+    /// Stores the given connection_end at path, buffered through a
+    /// [`super::txn::PendingWriteSet`] and applied via
+    /// [`super::txn::PendingWriteSet::apply_with`] rather than writing
+    /// straight to `connection_end_store`. With no `types.rs` field to hold
+    /// a buffer across calls, this only gives the single write itself
+    /// commit/rollback shape, not atomicity spanning a whole handler; see
+    /// the module comment above this `impl` block for why that's as far as
+    /// this tree can take it.
     fn store_connection(
         &mut self,
         connection_path: &ConnectionPath,
         connection_end: ConnectionEnd,
     ) -> Result<(), ContextError> {
-        self.ibc_store
-            .connection_end_store
-            .set(connection_path.clone(), connection_end)
-            .map_err(|_| ConnectionError::Other {
-                description: "Connection end store error".to_string(),
-            })?;
+        let mut pending = PendingWriteSet::new();
+        pending.set(connection_path.clone(), connection_end);
+
+        let store = &mut self.ibc_store.connection_end_store;
+        let mut result = Ok(());
+        pending.apply_with(|path, value| {
+            let Some(connection_end) = value else {
+                return;
+            };
+            if let Err(e) = store.set(path, connection_end) {
+                result = Err(ConnectionError::Other {
+                    description: format!("Connection end store error: {e:?}"),
+                });
+            }
+        });
+        result?;
         Ok(())
     }
 
@@ -797,17 +1185,31 @@ where
     }
 
     /// Stores the given channel_end at a path associated with the port_id and channel_id.
+    /// Stores the given channel_end at path, buffered through a
+    /// [`super::txn::PendingWriteSet`]; see [`Self::store_connection`] for
+    /// why this only covers the single write, not a whole handler's worth
+    /// of commit/rollback atomicity.
     fn store_channel(
         &mut self,
         channel_end_path: &ChannelEndPath,
         channel_end: ChannelEnd,
     ) -> Result<(), ContextError> {
-        self.ibc_store
-            .channel_end_store
-            .set(channel_end_path.clone(), channel_end)
-            .map_err(|_| ChannelError::Other {
-                description: "Channel end store error".to_string(),
-            })?;
+        let mut pending = PendingWriteSet::new();
+        pending.set(channel_end_path.clone(), channel_end);
+
+        let store = &mut self.ibc_store.channel_end_store;
+        let mut result = Ok(());
+        pending.apply_with(|path, value| {
+            let Some(channel_end) = value else {
+                return;
+            };
+            if let Err(e) = store.set(path, channel_end) {
+                result = Err(ChannelError::Other {
+                    description: format!("Channel end store error: {e:?}"),
+                });
+            }
+        });
+        result?;
         Ok(())
     }
 
@@ -866,11 +1268,19 @@ where
         Ok(())
     }
 
+    /// Pushes `event` onto the flat `events` buffer, which stays the
+    /// authoritative store since `MockIbcStore`'s field definition (in
+    /// `ibc-testkit`'s `types.rs`, not present in this tree) fixes its type
+    /// as `Mutex<Vec<IbcEvent>>`, not `Mutex<IbcEventLog>`. Queries go
+    /// through [`Self::events_at`] instead, which rebuilds a real
+    /// [`super::events::IbcEventLog`] from this buffer on demand.
     fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
         self.ibc_store.events.lock().push(event);
         Ok(())
     }
 
+    /// Pushes `message` onto the flat `logs` buffer; see
+    /// [`Self::emit_ibc_event`] and [`Self::logs_at`].
     fn log_message(&mut self, message: String) -> Result<(), ContextError> {
         self.ibc_store.logs.lock().push(message);
         Ok(())
@@ -880,3 +1290,156 @@ where
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::channel::{Counterparty as ChannelCounterparty, State as ChannelState};
+    use ibc::core::channel::types::Version as ChannelVersion;
+    use ibc::core::connection::types::version::get_compatible_versions;
+    use ibc::core::connection::types::{
+        ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+    };
+    use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+    use ibc::core::host::types::path::ConnectionPath;
+    use ibc::core::host::{ExecutionContext, ValidationContext};
+    use ibc::core::primitives::ZERO_DURATION;
+    use ibc_proto::Protobuf;
+
+    use super::*;
+    use crate::fixtures::core::connection::dummy_raw_counterparty_conn;
+    use crate::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+    use crate::testapp::ibc::core::types::{MockClientConfig, MockContext};
+
+    fn context_with_connection() -> (MockContext, ConnectionId, ConnectionEnd) {
+        let client_id = mock_client_type().build_client_id(24);
+        let default_context = MockContext::default();
+        let client_consensus_state_height = default_context.host_height().unwrap();
+        let conn_id = ConnectionId::new(0);
+
+        let conn_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            client_id.clone(),
+            ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+            get_compatible_versions(),
+            ZERO_DURATION,
+        )
+        .unwrap();
+
+        let context = default_context
+            .with_client_config(
+                MockClientConfig::builder()
+                    .client_id(client_id)
+                    .latest_height(client_consensus_state_height)
+                    .build(),
+            )
+            .with_connection(conn_id.clone(), conn_end.clone());
+
+        (context, conn_id, conn_end)
+    }
+
+    #[test]
+    fn verify_membership_succeeds_for_stored_connection_end() {
+        let (context, conn_id, conn_end) = context_with_connection();
+        let height = context.host_height().unwrap();
+        let path = Path::Connection(ConnectionPath::new(&conn_id));
+
+        context
+            .verify_membership(height, &path, conn_end.encode_vec())
+            .expect("membership verification should succeed for a stored connection end");
+    }
+
+    #[test]
+    fn verify_membership_fails_for_wrong_value() {
+        let (context, conn_id, _conn_end) = context_with_connection();
+        let height = context.host_height().unwrap();
+        let path = Path::Connection(ConnectionPath::new(&conn_id));
+
+        assert!(context
+            .verify_membership(height, &path, b"not the stored connection end".to_vec())
+            .is_err());
+    }
+
+    #[test]
+    fn verify_non_membership_fails_for_stored_connection_end() {
+        let (context, conn_id, _conn_end) = context_with_connection();
+        let height = context.host_height().unwrap();
+        let path = Path::Connection(ConnectionPath::new(&conn_id));
+
+        assert!(
+            context.verify_non_membership(height, &path).is_err(),
+            "a stored connection end should not pass non-membership verification"
+        );
+    }
+
+    #[test]
+    fn unreceived_packets_for_ordered_channel_uses_next_sequence_recv() {
+        let port_id: PortId = "transfer".parse().unwrap();
+        let channel_id = ChannelId::new(0);
+        let channel_end_path = ChannelEndPath::new(&port_id, &channel_id);
+
+        let chan_end = ChannelEnd::new(
+            ChannelState::Open,
+            Order::Ordered,
+            ChannelCounterparty::new(port_id.clone(), Some(channel_id.clone())),
+            vec![ConnectionId::new(0)],
+            ChannelVersion::default(),
+        )
+        .unwrap();
+
+        let mut context =
+            MockContext::default().with_channel(port_id.clone(), channel_id.clone(), chan_end);
+
+        context
+            .store_next_sequence_recv(
+                &SeqRecvPath::new(&port_id, &channel_id),
+                Sequence::from(3),
+            )
+            .unwrap();
+
+        let unreceived = context
+            .unreceived_packets(
+                &channel_end_path,
+                [1u64, 2, 3, 4].into_iter().map(Sequence::from),
+            )
+            .unwrap();
+
+        assert_eq!(unreceived, vec![Sequence::from(3), Sequence::from(4)]);
+    }
+
+    #[test]
+    fn connection_ends_paginated_limits_and_reports_next_key() {
+        let (context, conn_id_0, conn_end) = context_with_connection();
+        let conn_id_1 = ConnectionId::new(1);
+        let context = context.with_connection(conn_id_1.clone(), conn_end);
+
+        let request = PageRequest {
+            key: Vec::new(),
+            offset: 0,
+            limit: 1,
+            count_total: true,
+            reverse: false,
+        };
+        let (page, response) = context.connection_ends_paginated(&request).unwrap();
+
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].connection_id, conn_id_0);
+        assert_eq!(response.total, 2);
+        assert_eq!(response.next_key, conn_id_1.to_string().into_bytes());
+    }
+
+    #[test]
+    fn connection_ends_matches_unbounded_pagination() {
+        let (context, conn_id_0, conn_end) = context_with_connection();
+        let conn_id_1 = ConnectionId::new(1);
+        let context = context.with_connection(conn_id_1, conn_end);
+
+        let all = context.connection_ends().unwrap();
+        let (paginated, _) = context
+            .connection_ends_paginated(&unbounded_page_request())
+            .unwrap();
+
+        assert_eq!(all.len(), 2);
+        assert_eq!(all, paginated);
+        assert_eq!(all[0].connection_id, conn_id_0);
+    }
+}
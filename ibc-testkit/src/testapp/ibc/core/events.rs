@@ -0,0 +1,154 @@
+//! Height-indexed storage for emitted `IbcEvent`s and log lines, with a
+//! bounded retention window and typed ABCI attribute export.
+//!
+//! `MockIbcStore`'s `events`/`logs` fields (defined in `ibc-testkit`'s
+//! `types.rs`, not present in this tree) are fixed as flat
+//! `Mutex<Vec<IbcEvent>>`/`Mutex<Vec<String>>` buffers with no per-entry
+//! height, so [`HeightIndexedEventLog`] can't be substituted in as their
+//! backing storage. `MockGenericContext::event_log`
+//! (`testapp/ibc/core/core_ctx.rs`) instead rebuilds one from those buffers
+//! on every query, attributing everything currently buffered to the current
+//! height, and serves `events_at`/`logs_at` from it — real use of this
+//! module's indexing logic, short of the full cross-height history a stored
+//! field would give. That rebuilt log has no retention window, so its
+//! pruning never evicts anything. It's generic over the
+//! event type so the indexing/retention logic is independently testable;
+//! [`IbcEventLog`] is the concrete alias over the real [`IbcEvent`].
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use ibc::core::handler::types::events::IbcEvent;
+use tendermint::abci::Event as AbciEvent;
+
+use super::txn::RetentionPolicy;
+
+/// Height-indexed, bounded-retention store for emitted events and log
+/// lines.
+#[derive(Clone, Debug)]
+pub struct HeightIndexedEventLog<E> {
+    events: BTreeMap<u64, Vec<E>>,
+    logs: BTreeMap<u64, Vec<String>>,
+    /// Number of recent heights to keep; `None` retains every height.
+    retention: Option<u64>,
+}
+
+impl<E> Default for HeightIndexedEventLog<E> {
+    fn default() -> Self {
+        Self {
+            events: BTreeMap::new(),
+            logs: BTreeMap::new(),
+            retention: None,
+        }
+    }
+}
+
+impl<E> HeightIndexedEventLog<E> {
+    pub fn new(retention: Option<u64>) -> Self {
+        Self {
+            retention,
+            ..Self::default()
+        }
+    }
+
+    pub fn record_event(&mut self, height: u64, event: E) {
+        self.events.entry(height).or_default().push(event);
+        self.prune(height);
+    }
+
+    pub fn record_log(&mut self, height: u64, line: String) {
+        self.logs.entry(height).or_default().push(line);
+        self.prune(height);
+    }
+
+    pub fn events_at(&self, height: u64) -> &[E] {
+        self.events.get(&height).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn logs_at(&self, height: u64) -> &[String] {
+        self.logs.get(&height).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Keeps only the `retention` most recent heights ending at
+    /// `current_height` (always including `current_height` itself), called
+    /// after every write so buffers never grow past the window regardless of
+    /// which heights are written to. Delegates to [`RetentionPolicy::prune`]
+    /// with a window of `retention - 1` blocks behind `current_height`
+    /// rather than re-deriving the cutoff itself, so there's one eviction
+    /// rule shared with the write-buffer side in `super::txn`.
+    fn prune(&mut self, current_height: u64) {
+        let policy = RetentionPolicy::new(self.retention.map(|r| r.saturating_sub(1)));
+        policy.prune(&mut self.events, current_height);
+        policy.prune(&mut self.logs, current_height);
+    }
+}
+
+/// The concrete event log this crate's mock host emits into.
+pub type IbcEventLog = HeightIndexedEventLog<IbcEvent>;
+
+impl HeightIndexedEventLog<IbcEvent> {
+    /// Canonical ABCI `(type, attributes)` pairs for every event recorded at
+    /// `height`, via `IbcEvent`'s own `TryFrom<IbcEvent> for
+    /// tendermint::abci::Event` (the same conversion a host forwards to
+    /// Tendermint's event system). Skips events that conversion rejects
+    /// rather than failing the whole query, so one malformed event doesn't
+    /// hide the rest.
+    pub fn abci_attributes_at(&self, height: u64) -> Vec<(String, Vec<(String, String)>)> {
+        self.events_at(height)
+            .iter()
+            .filter_map(|event| AbciEvent::try_from(event.clone()).ok())
+            .map(|abci_event| {
+                let attributes = abci_event
+                    .attributes
+                    .into_iter()
+                    .map(|attr| (attr.key.to_string(), attr.value.to_string()))
+                    .collect();
+                (abci_event.kind, attributes)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_and_logs_are_indexed_by_height() {
+        let mut log = HeightIndexedEventLog::<&'static str>::new(None);
+        log.record_event(1, "a");
+        log.record_event(1, "b");
+        log.record_event(2, "c");
+        log.record_log(1, "height 1 log".to_string());
+
+        assert_eq!(log.events_at(1), &["a", "b"]);
+        assert_eq!(log.events_at(2), &["c"]);
+        assert!(log.events_at(3).is_empty());
+        assert_eq!(log.logs_at(1), &["height 1 log".to_string()]);
+        assert!(log.logs_at(2).is_empty());
+    }
+
+    #[test]
+    fn retention_window_evicts_old_heights_on_write() {
+        let mut log = HeightIndexedEventLog::<&'static str>::new(Some(2));
+        log.record_event(1, "a");
+        log.record_event(2, "b");
+        log.record_event(3, "c");
+
+        assert!(log.events_at(1).is_empty());
+        assert_eq!(log.events_at(2), &["b"]);
+        assert_eq!(log.events_at(3), &["c"]);
+    }
+
+    #[test]
+    fn unbounded_retention_keeps_every_height() {
+        let mut log = HeightIndexedEventLog::<&'static str>::new(None);
+        for height in 0..100 {
+            log.record_event(height, "e");
+        }
+
+        assert_eq!(log.events_at(0), &["e"]);
+        assert_eq!(log.events_at(99), &["e"]);
+    }
+}
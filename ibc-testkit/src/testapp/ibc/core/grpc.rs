@@ -0,0 +1,124 @@
+//! A `serve_grpc` harness that mounts the core IBC query services over a
+//! shared [`MockGenericContext`], so integration tests and relayer-against-
+//! mock setups can hit a real gRPC endpoint instead of calling
+//! `ValidationContext`/`QueryContext` trait methods directly.
+//!
+//! Only `ClientQuery` is mounted today. ibc-query also ships blanket
+//! `QueryService`s for `ConnectionQuery`/`ChannelQuery` (see
+//! [`ibc_query::core::connection::grpc`], [`ibc_query::core::channel::grpc`]),
+//! but those require a type that implements the full `ValidationContext` +
+//! `QueryContext`, and [`SharedMockContext`] deliberately only forwards the
+//! narrower [`ClientValidationContext`]/[`ClientListProvider`] surface to
+//! sidestep the associated-type ambiguity of implementing both on one type
+//! (see [`ibc_query::core::client::grpc::ClientListProvider`]'s doc comment).
+//! Mounting them here would need a second wrapper delegating the broader
+//! traits directly to [`MockGenericContext`].
+
+use alloc::fmt::Debug;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use basecoin_store::context::ProvableStore;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::{ClientConsensusStatePath, Path};
+use ibc::core::host::ValidationContext;
+use ibc::primitives::proto::Any;
+use ibc_proto::ibc::core::client::v1::query_server::QueryServer;
+use ibc_query::core::client::grpc::{ClientListProvider, ProofProvider, QueryService};
+use ibc_query::core::context::{ProvableContext, QueryContext};
+use tonic::transport::Server;
+
+use super::types::MockGenericContext;
+use crate::hosts::TestHost;
+
+/// A cheaply-cloneable handle to a [`MockGenericContext`], shared across the
+/// tonic service instances `serve_grpc` mounts.
+pub struct SharedMockContext<S, H>(Arc<MockGenericContext<S, H>>);
+
+impl<S, H> Clone for SharedMockContext<S, H> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<S, H> SharedMockContext<S, H> {
+    pub fn new(ctx: MockGenericContext<S, H>) -> Self {
+        Self(Arc::new(ctx))
+    }
+}
+
+impl<S, H> ClientValidationContext for SharedMockContext<S, H>
+where
+    S: ProvableStore + Debug,
+    H: TestHost,
+{
+    type AnyClientState = <MockGenericContext<S, H> as ValidationContext>::AnyClientState;
+    type AnyConsensusState = <MockGenericContext<S, H> as ValidationContext>::AnyConsensusState;
+
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::AnyClientState, ContextError> {
+        self.0.client_state(client_id)
+    }
+
+    fn decode_client_state(&self, client_state: Any) -> Result<Self::AnyClientState, ContextError> {
+        self.0.decode_client_state(client_state)
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::AnyConsensusState, ContextError> {
+        self.0.consensus_state(client_cons_state_path)
+    }
+}
+
+impl<S, H> ClientListProvider for SharedMockContext<S, H>
+where
+    S: ProvableStore + Debug,
+    H: TestHost,
+{
+    fn client_states(&self) -> Result<Vec<(ClientId, Self::AnyClientState)>, ContextError> {
+        QueryContext::client_states(self.0.as_ref())
+    }
+
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        QueryContext::consensus_state_heights(self.0.as_ref(), client_id)
+    }
+}
+
+impl<S, H> ProofProvider for SharedMockContext<S, H>
+where
+    S: ProvableStore + Debug,
+    H: TestHost,
+{
+    fn query_height(&self) -> Height {
+        self.0
+            .host_height()
+            .expect("mock host always has at least one block")
+    }
+
+    fn get_proof(&self, height: Height, path: &Path) -> Option<Vec<u8>> {
+        self.0.get_proof(height, path)
+    }
+}
+
+/// Serves the IBC client gRPC query service (`ClientQuery`) backed by `ctx`
+/// until the returned future is dropped or the server errors. The
+/// connection and channel query services are not mounted.
+pub async fn serve_grpc<S, H>(
+    addr: SocketAddr,
+    ctx: MockGenericContext<S, H>,
+) -> Result<(), tonic::transport::Error>
+where
+    S: ProvableStore + Debug + Send + Sync + 'static,
+    H: TestHost + Send + Sync + 'static,
+{
+    let shared = SharedMockContext::new(ctx);
+
+    Server::builder()
+        .add_service(QueryServer::new(QueryService::new(shared)))
+        .serve(addr)
+        .await
+}
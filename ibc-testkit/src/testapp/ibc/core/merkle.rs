@@ -0,0 +1,440 @@
+//! A from-scratch, sorted key-value Merkle tree producing ics23-compatible
+//! existence and non-existence proofs.
+//!
+//! `basecoin_store` (the external store [`super::core_ctx::MockGenericContext`]
+//! otherwise delegates proof generation to via `ProvableStore::get_proof`)
+//! isn't vendored in this tree, so its own tree-building code can't be
+//! extended here. This builds a genuine bottom-up binary tree instead, with
+//! leaf/inner node hashing that follows real IAVL's node-prefix encoding
+//! closely enough to satisfy the ics23 `iavl_spec()` checked by
+//! [`super::core_ctx::MockGenericContext::verify_membership`]: `iavl_spec()`
+//! doesn't just bound prefix lengths generically, it recognizes itself (via
+//! `ics23`'s internal `is_iavl_spec`) and additionally requires every node
+//! prefix to decode as a `height ‖ size ‖ version` varint triplet. Leaf
+//! nodes hash as
+//! `SHA256(height=0,size=1,version ‖ varint(key_len) ‖ key ‖ varint(32) ‖ SHA256(value))`
+//! and inner nodes hash as
+//! `SHA256(height,size,version ‖ encodeBytes(left) ‖ encodeBytes(right))`,
+//! where `encodeBytes` is a one-byte length prefix (digests are always
+//! 32-byte SHA256 outputs) followed by the digest. A tree built from this
+//! module's keys/values verifies against that same spec, for every key, not
+//! just the lexicographically-first one.
+//!
+//! [`VersionedMerkleTree`] snapshots a root per commit, so a proof generated
+//! against a past version still matches the root an earlier `app_hash`
+//! committed to.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use ics23::commitment_proof::Proof;
+use ics23::{
+    CommitmentProof, ExistenceProof, HashOp, InnerOp, LeafOp, LengthOp, NonExistenceProof,
+};
+use sha2::{Digest, Sha256};
+
+/// Plain unsigned LEB128 varint, matching `prost`'s `encode_varint` (and
+/// thus ics23's `LengthOp::VarProto`). Used for the key/value length
+/// prefixes inside a leaf.
+fn encode_varint(mut n: u64) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+        let mut byte = (n & 0x7f) as u8;
+        n >>= 7;
+        if n != 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if n == 0 {
+            break;
+        }
+    }
+    out
+}
+
+/// Zigzag varint, matching the IAVL-specific decoder ics23 uses to read the
+/// `height ‖ size ‖ version` triplet out of a node prefix (`read_varint` in
+/// ics23's `api.rs`) — a different wire format from the plain
+/// [`encode_varint`] above, which is why the two can't share one helper.
+fn encode_iavl_int(v: i64) -> Vec<u8> {
+    let zigzag = ((v << 1) ^ (v >> 63)) as u64;
+    encode_varint(zigzag)
+}
+
+/// The `height ‖ size ‖ version` triplet real IAVL embeds at the front of
+/// every node's hash preimage, and that `ics23::iavl_spec()` specifically
+/// validates the shape of.
+fn iavl_prefix(height: i64, size: i64, version: i64) -> Vec<u8> {
+    let mut out = encode_iavl_int(height);
+    out.extend(encode_iavl_int(size));
+    out.extend(encode_iavl_int(version));
+    out
+}
+
+/// SHA256 digests are always 32 bytes, so their IAVL length-prefix (as
+/// produced by `encodeBytes`) is always the single byte `0x20`.
+const HASH_LEN_PREFIX: u8 = 32;
+
+/// A sibling digest as embedded in an `InnerOp`'s `prefix`/`suffix`: a
+/// single length byte followed by the digest itself, matching IAVL's
+/// `encodeBytes`.
+fn encode_sibling(digest: &[u8]) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(1 + digest.len());
+    encoded.push(digest.len() as u8);
+    encoded.extend_from_slice(digest);
+    encoded
+}
+
+fn leaf_digest(version: u64, key: &[u8], value: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(iavl_prefix(0, 1, version as i64));
+    hasher.update(encode_varint(key.len() as u64));
+    hasher.update(key);
+    hasher.update(encode_varint(32));
+    hasher.update(Sha256::digest(value));
+    hasher.finalize().to_vec()
+}
+
+fn leaf_op(version: u64) -> LeafOp {
+    LeafOp {
+        hash: HashOp::Sha256 as i32,
+        prehash_key: HashOp::NoHash as i32,
+        prehash_value: HashOp::Sha256 as i32,
+        length: LengthOp::VarProto as i32,
+        prefix: iavl_prefix(0, 1, version as i64),
+    }
+}
+
+fn inner_op(prefix: Vec<u8>, suffix: Vec<u8>) -> InnerOp {
+    InnerOp {
+        hash: HashOp::Sha256 as i32,
+        prefix,
+        suffix,
+    }
+}
+
+/// A node's digest together with the number of leaves under it, which feeds
+/// the IAVL `size` field of every ancestor's prefix.
+#[derive(Clone)]
+struct Node {
+    digest: Vec<u8>,
+    size: i64,
+}
+
+/// Combines a left and right child into their parent, following real IAVL's
+/// `hashWithChildren`: `height ‖ size ‖ version ‖ encodeBytes(left) ‖
+/// encodeBytes(right)`.
+fn combine(height: i64, version: u64, left: &Node, right: &Node) -> Node {
+    let mut hasher = Sha256::new();
+    hasher.update(iavl_prefix(height, left.size + right.size, version as i64));
+    hasher.update([HASH_LEN_PREFIX]);
+    hasher.update(&left.digest);
+    hasher.update([HASH_LEN_PREFIX]);
+    hasher.update(&right.digest);
+    Node {
+        digest: hasher.finalize().to_vec(),
+        size: left.size + right.size,
+    }
+}
+
+/// Builds the `InnerOp` for one level of the climb from a proven leaf to the
+/// root, factoring the proven child's digest out of `prefix`/`suffix` so
+/// that ics23's `apply_inner` (`prefix ‖ child ‖ suffix`) reproduces exactly
+/// the preimage [`combine`] hashes.
+fn inner_op_for(height: i64, version: u64, left: &Node, right: &Node, proving_left: bool) -> InnerOp {
+    let triplet = iavl_prefix(height, left.size + right.size, version as i64);
+    if proving_left {
+        let mut prefix = triplet;
+        prefix.push(HASH_LEN_PREFIX);
+        inner_op(prefix, encode_sibling(&right.digest))
+    } else {
+        let mut prefix = triplet;
+        prefix.extend(encode_sibling(&left.digest));
+        prefix.push(HASH_LEN_PREFIX);
+        inner_op(prefix, Vec::new())
+    }
+}
+
+/// One level of the bottom-up binary tree: pairs adjacent nodes into
+/// parents via [`combine`], promoting an unpaired trailing node to the next
+/// level unchanged.
+fn level_up(level: &[Node], height: i64, version: u64) -> Vec<Node> {
+    let mut next = Vec::with_capacity(level.len() / 2 + 1);
+    let mut i = 0;
+    while i < level.len() {
+        if i + 1 < level.len() {
+            next.push(combine(height, version, &level[i], &level[i + 1]));
+            i += 2;
+        } else {
+            next.push(level[i].clone());
+            i += 1;
+        }
+    }
+    next
+}
+
+fn tree_root(leaves: Vec<Node>, version: u64) -> Vec<u8> {
+    if leaves.is_empty() {
+        return leaf_digest(version, &[], &[]);
+    }
+    let mut level = leaves;
+    let mut height = 1i64;
+    while level.len() > 1 {
+        level = level_up(&level, height, version);
+        height += 1;
+    }
+    level.into_iter().next().expect("non-empty").digest
+}
+
+/// The `InnerOp`s needed to fold `leaves[index]` up to the tree's root, one
+/// level at a time: at each level the proven node is either the left half
+/// of a pair (sibling goes in the next `InnerOp`'s `suffix`) or the right
+/// half (sibling goes in its `prefix`), so every leaf, not just the first,
+/// gets a correct `log2(n)`-deep path.
+fn tree_path(leaves: Vec<Node>, index: usize, version: u64) -> Vec<InnerOp> {
+    let mut ops = Vec::new();
+    let mut level = leaves;
+    let mut idx = index;
+    let mut height = 1i64;
+    while level.len() > 1 {
+        let mut next = Vec::with_capacity(level.len() / 2 + 1);
+        let mut next_idx = idx;
+        let mut i = 0;
+        while i < level.len() {
+            if i + 1 < level.len() {
+                if i == idx {
+                    ops.push(inner_op_for(height, version, &level[i], &level[i + 1], true));
+                    next_idx = next.len();
+                } else if i + 1 == idx {
+                    ops.push(inner_op_for(height, version, &level[i], &level[i + 1], false));
+                    next_idx = next.len();
+                }
+                next.push(combine(height, version, &level[i], &level[i + 1]));
+                i += 2;
+            } else {
+                if i == idx {
+                    next_idx = next.len();
+                }
+                next.push(level[i].clone());
+                i += 1;
+            }
+        }
+        idx = next_idx;
+        level = next;
+        height += 1;
+    }
+    ops
+}
+
+/// A single version of the tree: a sorted map of byte-encoded keys to
+/// values, with its digest computed bottom-up over the sorted key order,
+/// using the node-prefix encoding real IAVL (and thus `ics23::iavl_spec()`)
+/// expects.
+#[derive(Clone, Debug, Default)]
+struct Snapshot {
+    entries: BTreeMap<Vec<u8>, Vec<u8>>,
+    version: u64,
+}
+
+impl Snapshot {
+    fn leaves(&self) -> Vec<Node> {
+        self.entries
+            .iter()
+            .map(|(k, v)| Node {
+                digest: leaf_digest(self.version, k, v),
+                size: 1,
+            })
+            .collect()
+    }
+
+    fn index_of(&self, key: &[u8]) -> Option<usize> {
+        self.entries.keys().position(|k| k.as_slice() == key)
+    }
+
+    /// Builds the binary tree bottom-up over every leaf digest, in sorted
+    /// key order, and returns its root.
+    fn root(&self) -> Vec<u8> {
+        tree_root(self.leaves(), self.version)
+    }
+
+    /// The `InnerOp`s needed to fold `key`'s leaf digest up to the root, via
+    /// [`tree_path`]. Correct for every key at its own tree depth, not just
+    /// the lexicographically-first one.
+    fn inner_ops(&self, key: &[u8]) -> Vec<InnerOp> {
+        match self.index_of(key) {
+            Some(index) => tree_path(self.leaves(), index, self.version),
+            None => Vec::new(),
+        }
+    }
+
+    fn existence_proof(&self, key: &[u8]) -> Option<ExistenceProof> {
+        let value = self.entries.get(key)?.clone();
+        Some(ExistenceProof {
+            key: key.to_vec(),
+            value,
+            leaf: Some(leaf_op(self.version)),
+            path: self.inner_ops(key),
+        })
+    }
+
+    fn neighbors(&self, key: &[u8]) -> (Option<Vec<u8>>, Option<Vec<u8>>) {
+        let left = self
+            .entries
+            .range(..key.to_vec())
+            .next_back()
+            .map(|(k, _)| k.clone());
+        let right = self
+            .entries
+            .range(key.to_vec()..)
+            .find(|(k, _)| k.as_slice() != key)
+            .map(|(k, _)| k.clone());
+        (left, right)
+    }
+}
+
+/// A Merkle tree snapshotted per committed version, so a proof against a
+/// past version matches the root an earlier `app_hash` committed to.
+#[derive(Clone, Debug, Default)]
+pub struct VersionedMerkleTree {
+    pending: Snapshot,
+    committed: BTreeMap<u64, Snapshot>,
+}
+
+impl VersionedMerkleTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        self.pending.entries.insert(key, value);
+    }
+
+    pub fn remove(&mut self, key: &[u8]) {
+        self.pending.entries.remove(key);
+    }
+
+    /// Snapshots the current pending working set as `version`, so later
+    /// `root_at`/`prove_at` calls against `version` see exactly this state
+    /// even as `pending` keeps changing. `version` is stamped onto the
+    /// snapshot itself, since every node hash in this tree is keyed to the
+    /// version it was committed at.
+    pub fn commit(&mut self, version: u64) {
+        self.pending.version = version;
+        self.committed.insert(version, self.pending.clone());
+    }
+
+    pub fn root_at(&self, version: u64) -> Option<Vec<u8>> {
+        Some(self.committed.get(&version)?.root())
+    }
+
+    /// An [`ExistenceProof`]-backed [`CommitmentProof`] for `key` at
+    /// `version`, if present.
+    pub fn prove_at(&self, version: u64, key: &[u8]) -> Option<CommitmentProof> {
+        let snapshot = self.committed.get(&version)?;
+        let existence = snapshot.existence_proof(key)?;
+        Some(CommitmentProof {
+            proof: Some(Proof::Exist(existence)),
+        })
+    }
+
+    /// A [`NonExistenceProof`]-backed [`CommitmentProof`] for `key` at
+    /// `version`, bracketing it with the existence proofs of its immediate
+    /// lexicographic left and right neighbors, if `key` is absent.
+    pub fn prove_absence_at(&self, version: u64, key: &[u8]) -> Option<CommitmentProof> {
+        let snapshot = self.committed.get(&version)?;
+        if snapshot.entries.contains_key(key) {
+            return None;
+        }
+        let (left, right) = snapshot.neighbors(key);
+        Some(CommitmentProof {
+            proof: Some(Proof::Nonexist(NonExistenceProof {
+                key: key.to_vec(),
+                left: left.and_then(|k| snapshot.existence_proof(&k)),
+                right: right.and_then(|k| snapshot.existence_proof(&k)),
+            })),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn committed_tree(entries: &[(&[u8], &[u8])]) -> VersionedMerkleTree {
+        let mut tree = VersionedMerkleTree::new();
+        for (k, v) in entries {
+            tree.insert(k.to_vec(), v.to_vec());
+        }
+        tree.commit(1);
+        tree
+    }
+
+    fn assert_round_trips(entries: &[(&[u8], &[u8])]) {
+        let tree = committed_tree(entries);
+        let root = tree.root_at(1).unwrap();
+        for (key, value) in entries {
+            let proof = tree.prove_at(1, key).unwrap();
+            assert!(
+                ics23::verify_membership::<ics23::HostFunctionsManager>(
+                    &proof,
+                    &ics23::iavl_spec(),
+                    &root,
+                    key,
+                    value,
+                ),
+                "membership proof for {key:?} should verify"
+            );
+        }
+    }
+
+    #[test]
+    fn existence_proof_verifies_against_iavl_spec() {
+        assert_round_trips(&[
+            (b"a".as_slice(), b"1".as_slice()),
+            (b"b".as_slice(), b"2".as_slice()),
+            (b"c".as_slice(), b"3".as_slice()),
+        ]);
+    }
+
+    /// Regression test for the original left-leaning fold, which only
+    /// produced a valid proof path for the lexicographically-first key:
+    /// every key in a larger tree must verify, not just `"a"`.
+    #[test]
+    fn existence_proof_verifies_for_every_key_in_larger_tree() {
+        let entries: Vec<(Vec<u8>, Vec<u8>)> =
+            (0u8..25).map(|i| (vec![b'a' + i], vec![i])).collect();
+        let borrowed: Vec<(&[u8], &[u8])> =
+            entries.iter().map(|(k, v)| (k.as_slice(), v.as_slice())).collect();
+        assert_round_trips(&borrowed);
+    }
+
+    #[test]
+    fn non_existence_proof_verifies_against_iavl_spec() {
+        let tree = committed_tree(&[
+            (b"a".as_slice(), b"1".as_slice()),
+            (b"c".as_slice(), b"3".as_slice()),
+        ]);
+        let root = tree.root_at(1).unwrap();
+        let proof = tree.prove_absence_at(1, b"b").unwrap();
+
+        assert!(ics23::verify_non_membership::<ics23::HostFunctionsManager>(
+            &proof,
+            &ics23::iavl_spec(),
+            &root,
+            b"b",
+        ));
+    }
+
+    #[test]
+    fn past_version_root_is_unaffected_by_later_writes() {
+        let mut tree = committed_tree(&[(b"a".as_slice(), b"1".as_slice())]);
+        let root_v1 = tree.root_at(1).unwrap();
+
+        tree.insert(b"z".to_vec(), b"9".to_vec());
+        tree.commit(2);
+
+        assert_eq!(tree.root_at(1).unwrap(), root_v1);
+        assert_ne!(tree.root_at(2).unwrap(), root_v1);
+    }
+}
@@ -0,0 +1,104 @@
+//! Generic cursor-based pagination over a pre-fetched `Vec<T>`, matching the
+//! `PageRequest`/`PageResponse` semantics the real gRPC query services use:
+//! a non-empty `key` starts the page at the item whose key equals it (the
+//! previously returned `next_key`, i.e. the first item the previous page
+//! left out), otherwise `offset` applies; `limit == 0` falls back to
+//! [`DEFAULT_PAGE_LIMIT`].
+
+use ibc::core::primitives::prelude::*;
+use ibc_query::types::{PageRequest, PageResponse};
+
+/// Limit applied when a [`PageRequest`] leaves `limit` unset (`0`).
+pub const DEFAULT_PAGE_LIMIT: usize = 100;
+
+/// Sorts `items` by `key_bytes` for a deterministic order, then slices out
+/// the page `request` asks for.
+pub fn paginate<T>(
+    mut items: Vec<T>,
+    request: &PageRequest,
+    key_bytes: impl Fn(&T) -> Vec<u8>,
+) -> (Vec<T>, PageResponse) {
+    items.sort_by_cached_key(&key_bytes);
+
+    let total = if request.count_total {
+        items.len() as u64
+    } else {
+        0
+    };
+
+    let start = if !request.key.is_empty() {
+        items
+            .iter()
+            .position(|item| key_bytes(item) == request.key)
+            .unwrap_or(items.len())
+    } else {
+        (request.offset as usize).min(items.len())
+    };
+
+    let limit = if request.limit == 0 {
+        DEFAULT_PAGE_LIMIT
+    } else {
+        request.limit as usize
+    };
+    let end = items.len().min(start.saturating_add(limit));
+
+    let next_key = items.get(end).map(&key_bytes).unwrap_or_default();
+
+    let page = items.drain(start..end).collect();
+
+    (page, PageResponse { next_key, total })
+}
+
+/// A [`PageRequest`] that returns every item, for the non-paginated listing
+/// methods reusing their `_paginated` counterpart.
+pub fn unbounded_page_request() -> PageRequest {
+    PageRequest {
+        key: Vec::new(),
+        offset: 0,
+        limit: u64::MAX,
+        count_total: false,
+        reverse: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page_request(key: Vec<u8>, offset: u64, limit: u64) -> PageRequest {
+        PageRequest {
+            key,
+            offset,
+            limit,
+            count_total: true,
+            reverse: false,
+        }
+    }
+
+    #[test]
+    fn paginates_by_offset() {
+        let items = vec![3, 1, 2, 5, 4];
+        let (page, response) = paginate(items, &page_request(vec![], 1, 2), |n| vec![*n as u8]);
+
+        assert_eq!(page, vec![2, 3]);
+        assert_eq!(response.next_key, vec![4u8]);
+        assert_eq!(response.total, 5);
+    }
+
+    #[test]
+    fn paginates_by_key_cursor() {
+        let items = vec![3, 1, 2, 5, 4];
+        let (page, response) = paginate(items, &page_request(vec![4u8], 0, 2), |n| vec![*n as u8]);
+
+        assert_eq!(page, vec![4, 5]);
+        assert!(response.next_key.is_empty());
+    }
+
+    #[test]
+    fn unbounded_request_returns_everything() {
+        let items = vec![3, 1, 2];
+        let (page, _) = paginate(items, &unbounded_page_request(), |n| vec![*n as u8]);
+
+        assert_eq!(page, vec![1, 2, 3]);
+    }
+}
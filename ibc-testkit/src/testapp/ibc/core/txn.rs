@@ -0,0 +1,219 @@
+//! A generic transactional write buffer and height-based retention policy.
+//!
+//! Real atomicity for the `ExecutionContext` `store_*`/`delete_*`/
+//! `increase_*_counter` methods in `core_ctx.rs` needs to sit on `basecoin_store`'s own
+//! versioning primitives (whatever "promote pending to a new height" /
+//! "discard pending" API it exposes), which isn't vendored in this tree to
+//! build against. This implements the same commit/rollback shape standalone
+//! and generically instead: buffer writes/deletes against a
+//! [`PendingWriteSet`], call [`PendingWriteSet::commit`] to apply them
+//! atomically to a target map, or [`PendingWriteSet::rollback`] to discard
+//! them untouched on handler failure; [`RetentionPolicy`] evicts
+//! height-keyed entries (consensus states, packet commitment/receipt/ack)
+//! older than a configurable window.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// A single buffered write: either set a key to a value, or delete a key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum PendingOp<K, V> {
+    Set(K, V),
+    Delete(K),
+}
+
+/// Buffers `set`/`delete` calls against some target key-value map without
+/// touching it until [`Self::commit`], so a handler failure partway through
+/// a message can [`Self::rollback`] without leaving partially-applied
+/// writes behind.
+#[derive(Clone, Debug)]
+pub struct PendingWriteSet<K, V> {
+    ops: Vec<PendingOp<K, V>>,
+}
+
+impl<K, V> Default for PendingWriteSet<K, V> {
+    fn default() -> Self {
+        Self { ops: Vec::new() }
+    }
+}
+
+impl<K: Ord + Clone, V: Clone> PendingWriteSet<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, key: K, value: V) {
+        self.ops.push(PendingOp::Set(key, value));
+    }
+
+    pub fn delete(&mut self, key: K) {
+        self.ops.push(PendingOp::Delete(key));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Applies every buffered write/delete, in order, to `target`, then
+    /// clears the buffer. Returns the number of operations applied.
+    pub fn commit(&mut self, target: &mut BTreeMap<K, V>) -> usize {
+        let applied = self.ops.len();
+        for op in self.ops.drain(..) {
+            match op {
+                PendingOp::Set(k, v) => {
+                    target.insert(k, v);
+                }
+                PendingOp::Delete(k) => {
+                    target.remove(&k);
+                }
+            }
+        }
+        applied
+    }
+
+    /// Discards every buffered write/delete without touching `target`.
+    pub fn rollback(&mut self) {
+        self.ops.clear();
+    }
+
+    /// Applies every buffered write/delete, in order, through `sink` instead
+    /// of a `BTreeMap` directly, then clears the buffer. Returns the number
+    /// of operations applied.
+    ///
+    /// [`Self::commit`] only targets a `BTreeMap`, which doesn't fit the
+    /// typed, `basecoin_store`-backed stores `ExecutionContext` methods
+    /// write through (e.g. `connection_end_store`). This lets a single
+    /// write still buffer through a real `PendingWriteSet` and apply via
+    /// whatever `set`/`delete` call the target store actually exposes.
+    pub fn apply_with<F: FnMut(K, Option<V>)>(&mut self, mut sink: F) -> usize {
+        let applied = self.ops.len();
+        for op in self.ops.drain(..) {
+            match op {
+                PendingOp::Set(k, v) => sink(k, Some(v)),
+                PendingOp::Delete(k) => sink(k, None),
+            }
+        }
+        applied
+    }
+}
+
+/// Evicts height-keyed entries older than a configurable retention window.
+#[derive(Clone, Copy, Debug)]
+pub struct RetentionPolicy {
+    retention: Option<u64>,
+}
+
+impl RetentionPolicy {
+    pub fn new(retention: Option<u64>) -> Self {
+        Self { retention }
+    }
+
+    pub fn unbounded() -> Self {
+        Self { retention: None }
+    }
+
+    /// The oldest height `latest_height` still wants kept; entries older
+    /// than this should be evicted.
+    pub fn cutoff(&self, latest_height: u64) -> Option<u64> {
+        self.retention.map(|r| latest_height.saturating_sub(r))
+    }
+
+    /// Evicts every entry in `entries` keyed by a height older than the
+    /// cutoff for `latest_height`. Returns the number of entries evicted.
+    pub fn prune<V>(&self, entries: &mut BTreeMap<u64, V>, latest_height: u64) -> usize {
+        let Some(cutoff) = self.cutoff(latest_height) else {
+            return 0;
+        };
+        let before = entries.len();
+        entries.retain(|height, _| *height >= cutoff);
+        before - entries.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commit_applies_buffered_writes_in_order() {
+        let mut target = BTreeMap::new();
+        target.insert("b", 9);
+
+        let mut pending = PendingWriteSet::new();
+        pending.set("a", 1);
+        pending.set("a", 2);
+        pending.delete("b");
+
+        let applied = pending.commit(&mut target);
+
+        assert_eq!(applied, 3);
+        assert_eq!(target.get("a"), Some(&2));
+        assert_eq!(target.get("b"), None);
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn apply_with_drives_an_arbitrary_sink_in_order() {
+        let mut target = BTreeMap::new();
+        target.insert("b", 9);
+
+        let mut pending = PendingWriteSet::new();
+        pending.set("a", 1);
+        pending.set("a", 2);
+        pending.delete("b");
+
+        let applied = pending.apply_with(|k, v| match v {
+            Some(v) => {
+                target.insert(k, v);
+            }
+            None => {
+                target.remove(k);
+            }
+        });
+
+        assert_eq!(applied, 3);
+        assert_eq!(target.get("a"), Some(&2));
+        assert_eq!(target.get("b"), None);
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn rollback_discards_buffered_writes() {
+        let mut target = BTreeMap::new();
+        target.insert("a", 1);
+
+        let mut pending = PendingWriteSet::new();
+        pending.set("a", 2);
+        pending.delete("a");
+        pending.rollback();
+
+        assert_eq!(target.get("a"), Some(&1));
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn retention_policy_prunes_entries_older_than_window() {
+        let mut entries = BTreeMap::new();
+        entries.insert(1u64, "v1");
+        entries.insert(2u64, "v2");
+        entries.insert(3u64, "v3");
+
+        let pruned = RetentionPolicy::new(Some(1)).prune(&mut entries, 3);
+
+        assert_eq!(pruned, 1);
+        assert!(!entries.contains_key(&1));
+        assert!(entries.contains_key(&2));
+        assert!(entries.contains_key(&3));
+    }
+
+    #[test]
+    fn unbounded_retention_never_prunes() {
+        let mut entries = BTreeMap::new();
+        entries.insert(1u64, "v1");
+
+        let pruned = RetentionPolicy::unbounded().prune(&mut entries, 1000);
+
+        assert_eq!(pruned, 0);
+        assert!(entries.contains_key(&1));
+    }
+}
@@ -0,0 +1,78 @@
+//! Dummy builders for client create/update/upgrade messages, used by
+//! [`crate::utils::trace`] to replay model-checker traces through the client
+//! handlers. Follows the same raw-proto round-trip as
+//! [`crate::utils::dummies::core::connection::conn_open_ack`].
+
+use ibc::core::client::types::msgs::{MsgCreateClient, MsgUpdateClient, MsgUpgradeClient};
+use ibc::core::client::types::proto::v1::{
+    MsgCreateClient as RawMsgCreateClient, MsgUpdateClient as RawMsgUpdateClient,
+    MsgUpgradeClient as RawMsgUpgradeClient,
+};
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::primitives::prelude::*;
+
+use crate::testapp::ibc::clients::mock::client_state::MockClientState;
+use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use crate::testapp::ibc::clients::mock::header::MockHeader;
+use crate::utils::dummies::core::channel::dummy_proof;
+use crate::utils::dummies::core::signer::dummy_bech32_account;
+
+pub fn dummy_msg_create_client(height: u64) -> MsgCreateClient {
+    MsgCreateClient::try_from(dummy_raw_msg_create_client(height)).expect("Never fails")
+}
+
+pub fn dummy_raw_msg_create_client(height: u64) -> RawMsgCreateClient {
+    let header_height = Height::new(0, height).expect("invalid height");
+    RawMsgCreateClient {
+        client_state: Some(MockClientState::new(MockHeader::new(header_height)).into()),
+        consensus_state: Some(MockConsensusState::new(MockHeader::new(header_height)).into()),
+        signer: dummy_bech32_account(),
+    }
+}
+
+pub fn dummy_msg_update_client(client_id: ClientId, height: u64) -> MsgUpdateClient {
+    MsgUpdateClient::try_from(dummy_raw_msg_update_client(client_id, height)).expect("Never fails")
+}
+
+pub fn dummy_raw_msg_update_client(client_id: ClientId, height: u64) -> RawMsgUpdateClient {
+    let header_height = Height::new(0, height).expect("invalid height");
+    RawMsgUpdateClient {
+        client_id: client_id.to_string(),
+        client_message: Some(MockHeader::new(header_height).into()),
+        signer: dummy_bech32_account(),
+    }
+}
+
+/// `revision_height` is the height at which the counterparty committed the
+/// upgraded states; `new_revision_number` is the bumped `ChainId` revision
+/// the upgraded client is expected to start counting from.
+pub fn dummy_msg_upgrade_client(
+    client_id: ClientId,
+    revision_height: u64,
+    new_revision_number: u64,
+) -> MsgUpgradeClient {
+    MsgUpgradeClient::try_from(dummy_raw_msg_upgrade_client(
+        client_id,
+        revision_height,
+        new_revision_number,
+    ))
+    .expect("Never fails")
+}
+
+pub fn dummy_raw_msg_upgrade_client(
+    client_id: ClientId,
+    revision_height: u64,
+    new_revision_number: u64,
+) -> RawMsgUpgradeClient {
+    let upgraded_height =
+        Height::new(new_revision_number, revision_height).expect("invalid height");
+    RawMsgUpgradeClient {
+        client_id: client_id.to_string(),
+        client_state: Some(MockClientState::new(MockHeader::new(upgraded_height)).into()),
+        consensus_state: Some(MockConsensusState::new(MockHeader::new(upgraded_height)).into()),
+        proof_upgrade_client: dummy_proof(),
+        proof_upgrade_consensus_state: dummy_proof(),
+        signer: dummy_bech32_account(),
+    }
+}
@@ -0,0 +1,211 @@
+//! A model-based-testing driver that replays externally generated traces
+//! (e.g. from a TLA+ or other model checker) through the IBC handlers.
+//!
+//! Unlike the one-off dummy message builders in [`crate::utils::dummies`],
+//! this module defines a serde-deserializable [`Trace`] of [`Step`]s and a
+//! [`TraceRunner`] that drives a [`MockContext`] through each step, building
+//! the corresponding message from the step's parameters, dispatching it,
+//! asserting the resulting success/failure against the step's
+//! `expected_outcome`, and then checking any [`ChainStateAssertion`]s the
+//! step carries against the resulting client/consensus state.
+
+use ibc::core::client::types::msgs::ClientMsg;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::msgs::ConnectionMsg;
+use ibc::core::entrypoint::execute;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::ClientConsensusStatePath;
+use ibc::core::host::ValidationContext;
+use serde::Deserialize;
+
+use crate::testapp::ibc::core::router::MockRouter;
+use crate::testapp::ibc::core::types::MockContext;
+use crate::utils::dummies::core::client::{
+    dummy_msg_create_client, dummy_msg_update_client, dummy_msg_upgrade_client,
+};
+use crate::utils::dummies::core::connection::conn_open_ack::dummy_msg_conn_open_ack;
+
+/// A height as it appears in a trace file, carrying both the revision
+/// number and the block height so that upgrade steps (which bump the
+/// `ChainId` revision) can be expressed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub struct TraceHeight {
+    pub revision_number: u64,
+    pub revision_height: u64,
+}
+
+impl From<TraceHeight> for Height {
+    fn from(height: TraceHeight) -> Self {
+        Height::new(height.revision_number, height.revision_height)
+            .expect("trace carries a valid height")
+    }
+}
+
+/// A single action a trace step asks the runner to perform.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum Action {
+    /// Drives a `MsgConnectionOpenAck` built from the `revision_height` of
+    /// each given height; their `revision_number`s are not used.
+    ConnOpenAck {
+        proof_height: TraceHeight,
+        consensus_height: TraceHeight,
+    },
+    /// Drives a `MsgCreateClient` for a mock client, as if it had just been
+    /// assigned `client_id` by the host's client counter. The client is
+    /// created at revision `0` and `height.revision_height`;
+    /// `height.revision_number` is not used.
+    CreateClient {
+        client_id: ClientId,
+        height: TraceHeight,
+    },
+    /// Drives a `MsgUpdateClient` advancing `client_id` to revision `0` at
+    /// `height.revision_height`; `height.revision_number` is not used.
+    UpdateClient {
+        client_id: ClientId,
+        height: TraceHeight,
+    },
+    /// Drives a `MsgUpgradeClient` for `client_id`, committing the upgraded
+    /// states at `revision_height` under the bumped `new_revision_number`.
+    UpgradeClient {
+        client_id: ClientId,
+        revision_height: u64,
+        new_revision_number: u64,
+    },
+}
+
+/// The outcome a trace step expects the action to produce.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpectedOutcome {
+    Success,
+    Error,
+}
+
+/// A post-step check against the runner's `MockContext`, run only when the
+/// step's `expected_outcome` is [`ExpectedOutcome::Success`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "check", rename_all = "snake_case")]
+pub enum ChainStateAssertion {
+    /// Asserts that a client state is stored for `client_id`.
+    ClientStateExists { client_id: ClientId },
+    /// Asserts that a consensus state is stored for `client_id` at `height`.
+    ConsensusStateExists {
+        client_id: ClientId,
+        height: TraceHeight,
+    },
+}
+
+/// One step of a trace: an action to perform, the outcome it should produce
+/// against the runner's current `MockContext`, and any resulting
+/// client/consensus state the trace expects to find afterwards.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Step {
+    #[serde(flatten)]
+    pub action: Action,
+    pub expected_outcome: ExpectedOutcome,
+    #[serde(default)]
+    pub chain_states: Vec<ChainStateAssertion>,
+}
+
+/// A full trace, replayed in order by [`TraceRunner::run`].
+pub type Trace = Vec<Step>;
+
+/// Deserializes a [`Trace`] from its JSON representation.
+pub fn load_trace(json: &str) -> serde_json::Result<Trace> {
+    serde_json::from_str(json)
+}
+
+/// Drives a [`MockContext`] through a [`Trace`], asserting that each step's
+/// outcome matches what the trace expects.
+pub struct TraceRunner {
+    ctx: MockContext,
+    router: MockRouter,
+}
+
+impl TraceRunner {
+    pub fn new(ctx: MockContext, router: MockRouter) -> Self {
+        Self { ctx, router }
+    }
+
+    /// Replays every step of `trace`, returning an error identifying the
+    /// first step whose actual outcome, or resulting chain state, diverges
+    /// from what the step expects.
+    pub fn run(&mut self, trace: &Trace) -> Result<(), String> {
+        for (i, step) in trace.iter().enumerate() {
+            let result = self.apply(&step.action);
+            match (step.expected_outcome, &result) {
+                (ExpectedOutcome::Success, Ok(())) => {}
+                (ExpectedOutcome::Error, Err(_)) => {}
+                _ => {
+                    return Err(format!(
+                        "step {i} ({:?}) expected {:?} but got {:?}",
+                        step.action, step.expected_outcome, result
+                    ))
+                }
+            }
+
+            if step.expected_outcome == ExpectedOutcome::Success {
+                for assertion in &step.chain_states {
+                    self.check(assertion)
+                        .map_err(|e| format!("step {i} ({:?}): {e}", step.action))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn apply(&mut self, action: &Action) -> Result<(), String> {
+        let envelope = match action {
+            Action::ConnOpenAck {
+                proof_height,
+                consensus_height,
+            } => {
+                let msg = dummy_msg_conn_open_ack(
+                    proof_height.revision_height,
+                    consensus_height.revision_height,
+                );
+                MsgEnvelope::from(ConnectionMsg::from(msg))
+            }
+            Action::CreateClient { height, .. } => {
+                let msg = dummy_msg_create_client(height.revision_height);
+                MsgEnvelope::from(ClientMsg::from(msg))
+            }
+            Action::UpdateClient { client_id, height } => {
+                let msg = dummy_msg_update_client(client_id.clone(), height.revision_height);
+                MsgEnvelope::from(ClientMsg::from(msg))
+            }
+            Action::UpgradeClient {
+                client_id,
+                revision_height,
+                new_revision_number,
+            } => {
+                let msg =
+                    dummy_msg_upgrade_client(client_id.clone(), *revision_height, *new_revision_number);
+                MsgEnvelope::from(ClientMsg::from(msg))
+            }
+        };
+        execute(&mut self.ctx, &mut self.router, envelope).map_err(|e| e.to_string())
+    }
+
+    /// Checks a single [`ChainStateAssertion`] against the runner's current
+    /// `MockContext`, via the same `ValidationContext` lookups the handlers
+    /// themselves use.
+    fn check(&self, assertion: &ChainStateAssertion) -> Result<(), String> {
+        match assertion {
+            ChainStateAssertion::ClientStateExists { client_id } => self
+                .ctx
+                .client_state(client_id)
+                .map(|_| ())
+                .map_err(|e| format!("expected a client state for {client_id}, got {e}")),
+            ChainStateAssertion::ConsensusStateExists { client_id, height } => {
+                let path = ClientConsensusStatePath::new(client_id, &Height::from(*height));
+                self.ctx
+                    .consensus_state(&path)
+                    .map(|_| ())
+                    .map_err(|e| format!("expected a consensus state for {client_id} at {height:?}, got {e}"))
+            }
+        }
+    }
+}